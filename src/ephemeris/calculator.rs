@@ -2,28 +2,155 @@
 //!
 //! This module provides safe Rust wrappers around the libswisseph-sys FFI bindings.
 
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono::{
+    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeDelta, TimeZone, Timelike,
+};
 use chrono_tz::Tz;
-use std::sync::Once;
+use std::cell::Cell;
+use std::ffi::CString;
+use std::fmt;
+use std::sync::{Mutex, Once, OnceLock};
 
-use crate::models::{Planet, ZodiacPosition, ZodiacSign};
+use super::fixed_stars::bundled_catalog_dir;
+use crate::models::{
+    AmbiguousTimePolicy, AspectType, Ayanamsa, Body, Planet, ZodiacMode, ZodiacPosition, ZodiacSign,
+};
 
 // Swiss Ephemeris constants
 const SE_GREG_CAL: i32 = 1;
 const SEFLG_SPEED: i32 = 256; // Include speed in calculations
 const SEFLG_SWIEPH: i32 = 2; // Use Swiss Ephemeris
+const SEFLG_SIDEREAL: i32 = 64 * 1024; // Sidereal positions
+const SEFLG_EQUATORIAL: i32 = 2048; // Right ascension and declination
+const SE_ECL_NUT: i32 = -1; // Obliquity of the ecliptic and nutation
 
-static INIT: Once = Once::new();
+/// Search path handed to Swiss Ephemeris, built on first use
+static SEARCH_PATH: OnceLock<Option<CString>> = OnceLock::new();
 
-/// Initialize Swiss Ephemeris (call once at startup)
+/// Directory (or list of directories) holding the Swiss Ephemeris `.se1` files
+static EPHEMERIS_PATH: OnceLock<Option<String>> = OnceLock::new();
+
+/// Environment variable naming the ephemeris directory
+pub const EPHEMERIS_PATH_ENV: &str = "STELLIUM_EPHE_PATH";
+
+/// Whether Swiss Ephemeris keeps its state (search path, sidereal mode) per thread.
+/// The C library is built with thread-local storage except on macOS and Windows,
+/// where the state is process-wide (see `TLS` in sweodef.h).
+const THREAD_LOCAL_STATE: bool = !cfg!(any(target_vendor = "apple", windows));
+
+/// With process-wide state, the sidereal mode is held for the duration of any
+/// sidereal calculation
+static SIDEREAL_MODE: Mutex<()> = Mutex::new(());
+
+/// Set the directory holding the Swiss Ephemeris `.se1` files (call before `init_ephemeris`)
+/// Several directories can be given, separated by ':' (';' on Windows).
+pub fn set_ephemeris_path(path: Option<String>) {
+    let _ = EPHEMERIS_PATH.set(path.filter(|p| !p.is_empty()));
+}
+
+/// The configured ephemeris directory, if any
+pub fn ephemeris_path() -> Option<&'static str> {
+    EPHEMERIS_PATH.get().and_then(|p| p.as_deref())
+}
+
+/// Initialize Swiss Ephemeris
+/// Every calculation calls this first. Where the state is per thread, the first call on
+/// each thread sets the search path; otherwise only the first call in the process does.
 pub fn init_ephemeris() {
-    INIT.call_once(|| {
-        unsafe {
-            // Initialize without ephemeris files (uses Moshier analytical ephemeris)
-            // This provides 0.1 arc seconds precision for planets, 3 arc seconds for Moon
+    static INIT: Once = Once::new();
+    thread_local! {
+        static INITIALIZED: Cell<bool> = const { Cell::new(false) };
+    }
+    if THREAD_LOCAL_STATE {
+        if !INITIALIZED.with(|initialized| initialized.replace(true)) {
+            set_search_path();
+        }
+    } else {
+        INIT.call_once(set_search_path);
+    }
+}
+
+/// Hand the configured ephemeris directories and the bundled star catalog to Swiss Ephemeris
+fn set_search_path() {
+    let search_path = SEARCH_PATH.get_or_init(|| {
+        // Without ephemeris files, planets fall back to the Moshier analytical ephemeris
+        // (0.1 arc seconds precision for planets, 3 arc seconds for the Moon), but Chiron
+        // and the asteroids cannot be calculated. The bundled star catalog comes last, so a
+        // full sefstars.txt in the configured directory wins.
+        let separator = if cfg!(windows) { ";" } else { ":" };
+        let directories: Vec<String> = ephemeris_path()
+            .map(str::to_string)
+            .into_iter()
+            .chain(
+                bundled_catalog_dir()
+                    .ok()
+                    .map(|dir| dir.display().to_string()),
+            )
+            .collect();
+        CString::new(directories.join(separator))
+            .ok()
+            .filter(|p| !p.is_empty())
+    });
+    match search_path {
+        Some(path) => unsafe {
+            libswisseph_sys::swe_set_ephe_path(path.as_ptr() as *mut _);
+        },
+        None => unsafe {
             libswisseph_sys::swe_set_ephe_path(std::ptr::null_mut());
+        },
+    }
+}
+
+/// Run a Swiss Ephemeris calculation in the given zodiac
+/// The closure receives the extra flags to pass along (SEFLG_SIDEREAL or nothing).
+pub(crate) fn with_zodiac<T>(zodiac: ZodiacMode, calc: impl FnOnce(i32) -> T) -> T {
+    match zodiac {
+        ZodiacMode::Tropical => calc(0),
+        ZodiacMode::Sidereal(ayanamsa) => {
+            // Per-thread state can't be changed by another thread mid-calculation
+            let _guard = (!THREAD_LOCAL_STATE)
+                .then(|| SIDEREAL_MODE.lock().unwrap_or_else(|e| e.into_inner()));
+            unsafe {
+                libswisseph_sys::swe_set_sid_mode(ayanamsa.swe_id(), 0.0, 0.0);
+            }
+            calc(SEFLG_SIDEREAL)
         }
+    }
+}
+
+/// Convert a Swiss Ephemeris error buffer into a String
+pub(crate) fn swe_error_message(serr: &[i8]) -> String {
+    unsafe {
+        let c_str = std::ffi::CStr::from_ptr(serr.as_ptr());
+        c_str.to_string_lossy().to_string()
+    }
+}
+
+/// Calculate the ayanamsa (sidereal offset in degrees) at a given Julian Day
+pub fn calc_ayanamsa(ayanamsa: Ayanamsa, julian_day: f64) -> Result<f64, String> {
+    init_ephemeris();
+
+    let mut value: f64 = 0.0;
+    let mut serr: [i8; 256] = [0; 256];
+
+    let ret = with_zodiac(ZodiacMode::Sidereal(ayanamsa), |_| unsafe {
+        libswisseph_sys::swe_get_ayanamsa_ex_ut(
+            julian_day,
+            SEFLG_SWIEPH,
+            &mut value,
+            serr.as_mut_ptr(),
+        )
     });
+
+    if ret < 0 {
+        return Err(format!(
+            "Swiss Ephemeris error: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    Ok(value)
 }
 
 /// Result of a planetary calculation
@@ -43,12 +170,23 @@ pub struct PlanetaryPosition {
     pub speed_distance: f64,
     /// Whether the planet is retrograde
     pub is_retrograde: bool,
+    /// Right ascension (0-360 degrees, always measured from the tropical equinox)
+    pub right_ascension: f64,
+    /// Declination (degrees north of the celestial equator, negative = south)
+    pub declination: f64,
+    /// Speed in declination (degrees per day)
+    pub speed_declination: f64,
 }
 
 impl PlanetaryPosition {
     pub fn to_zodiac_position(&self) -> ZodiacPosition {
         ZodiacPosition::from_longitude(self.longitude)
     }
+
+    /// Whether the declination lies beyond the Sun's greatest declination (the obliquity)
+    pub fn is_out_of_bounds(&self, obliquity: f64) -> bool {
+        self.declination.abs() > obliquity
+    }
 }
 
 /// Convert a date/time to Julian Day (UT)
@@ -69,58 +207,253 @@ pub fn date_to_julian_day(date: NaiveDate) -> f64 {
     datetime_to_julian_day(datetime)
 }
 
-/// Convert a local date/time with timezone to Julian Day (UT)
-pub fn local_datetime_to_julian_day(
+/// Timezone of a local date/time: an IANA zone, or a fixed offset from UTC
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LocalTimezone {
+    /// IANA timezone with its daylight saving rules (e.g., "America/New_York")
+    Named(Tz),
+    /// Fixed UTC offset, given directly ("+05:30") or as local mean time
+    Fixed(FixedOffset),
+}
+
+impl LocalTimezone {
+    /// Parse an IANA name, a raw UTC offset ("+05:30", "-0800", "UTC+3") or "LMT".
+    /// Local mean time needs the longitude: 4 minutes per degree east of Greenwich.
+    pub fn parse(timezone: &str, longitude: Option<f64>) -> Result<Self, String> {
+        let timezone = timezone.trim();
+
+        if timezone.eq_ignore_ascii_case("LMT") {
+            let longitude = longitude
+                .ok_or_else(|| "LMT (local mean time) requires a longitude".to_string())?;
+            return FixedOffset::east_opt((longitude * 240.0).round() as i32)
+                .map(LocalTimezone::Fixed)
+                .ok_or_else(|| format!("Invalid longitude for LMT: {}", longitude));
+        }
+
+        if let Ok(tz) = timezone.parse::<Tz>() {
+            return Ok(LocalTimezone::Named(tz));
+        }
+
+        parse_utc_offset(timezone).map(LocalTimezone::Fixed).ok_or_else(|| {
+            format!(
+                "Invalid timezone: {} (expected an IANA name like 'America/New_York', a UTC offset like '+05:30', or 'LMT')",
+                timezone
+            )
+        })
+    }
+
+    /// Local date and time at a UTC moment
+    pub fn from_utc(&self, utc: NaiveDateTime) -> DateTime<FixedOffset> {
+        match self {
+            LocalTimezone::Named(tz) => tz.from_utc_datetime(&utc).fixed_offset(),
+            LocalTimezone::Fixed(offset) => offset.from_utc_datetime(&utc),
+        }
+    }
+}
+
+impl fmt::Display for LocalTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalTimezone::Named(tz) => write!(f, "{}", tz.name()),
+            LocalTimezone::Fixed(offset) => write!(f, "UTC{}", offset),
+        }
+    }
+}
+
+/// Parse "+05:30", "-0800", "+5" or the same with a "UTC"/"GMT" prefix
+fn parse_utc_offset(offset: &str) -> Option<FixedOffset> {
+    let upper = offset.to_ascii_uppercase();
+    let rest = upper
+        .strip_prefix("UTC")
+        .or_else(|| upper.strip_prefix("GMT"))
+        .unwrap_or(&upper);
+
+    let (sign, digits) = if let Some(d) = rest.strip_prefix('+') {
+        (1, d)
+    } else if let Some(d) = rest.strip_prefix('-') {
+        (-1, d)
+    } else {
+        return None;
+    };
+
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, "00"),
+    };
+    if hours.is_empty() || hours.len() > 2 || minutes.len() != 2 {
+        return None;
+    }
+
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Resolve a local date/time to an instant, applying the policy to fall-back (repeated) hours
+pub fn resolve_local_datetime(
     date: NaiveDate,
     time: NaiveTime,
-    timezone: &str,
-) -> Result<f64, String> {
-    let tz: Tz = timezone
-        .parse()
-        .map_err(|_| format!("Invalid timezone: {}", timezone))?;
-
+    timezone: &LocalTimezone,
+    ambiguous: AmbiguousTimePolicy,
+) -> Result<DateTime<FixedOffset>, String> {
     let local_datetime = NaiveDateTime::new(date, time);
 
-    // Convert local time to UTC
-    let local_dt = tz
-        .from_local_datetime(&local_datetime)
-        .single()
-        .ok_or_else(|| "Ambiguous or invalid local time".to_string())?;
+    let tz = match timezone {
+        LocalTimezone::Fixed(offset) => {
+            return Ok(offset.from_utc_datetime(&(local_datetime - *offset)));
+        }
+        LocalTimezone::Named(tz) => tz,
+    };
+
+    match tz.from_local_datetime(&local_datetime) {
+        LocalResult::Single(dt) => Ok(dt.fixed_offset()),
+        LocalResult::Ambiguous(a, b) => {
+            let (earlier, later) = if a <= b { (a, b) } else { (b, a) };
+            match ambiguous {
+                AmbiguousTimePolicy::Earlier => Ok(earlier.fixed_offset()),
+                AmbiguousTimePolicy::Later => Ok(later.fixed_offset()),
+                AmbiguousTimePolicy::Reject => Err(format!(
+                    "Local time {} is ambiguous in {}: clocks fall back and it occurs twice, at UTC{} and UTC{}. Set ambiguous_time to 'earlier' or 'later'",
+                    local_datetime,
+                    tz.name(),
+                    earlier.offset().fix(),
+                    later.offset().fix()
+                )),
+            }
+        }
+        LocalResult::None => Err(format!(
+            "Local time {} does not exist in {}: clocks spring forward and skip it. Check the time, or give the UTC offset in use instead of a zone name",
+            local_datetime,
+            tz.name()
+        )),
+    }
+}
+
+/// Resolve a local date/time for a query rather than a birth: a repeated fall-back hour uses
+/// its first occurrence, and a time skipped when clocks spring forward moves on to the first
+/// valid instant (so midnight on a day that starts at 01:00 means 01:00)
+pub fn resolve_query_datetime(
+    date: NaiveDate,
+    time: NaiveTime,
+    timezone: &LocalTimezone,
+) -> Result<DateTime<FixedOffset>, String> {
+    let requested = NaiveDateTime::new(date, time);
+    let mut local = requested;
+    // Transitions fall on whole minutes, and no gap is longer than a day (Samoa skipped 2011-12-30)
+    while local - requested <= TimeDelta::days(1) {
+        if let Ok(resolved) = resolve_local_datetime(
+            local.date(),
+            local.time(),
+            timezone,
+            AmbiguousTimePolicy::Earlier,
+        ) {
+            return Ok(resolved);
+        }
+        local = local
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))
+            .unwrap_or(local)
+            + TimeDelta::minutes(1);
+    }
+    resolve_local_datetime(date, time, timezone, AmbiguousTimePolicy::Earlier)
+}
 
-    let utc_datetime = local_dt.with_timezone(&Utc).naive_utc();
+/// Convert a local date/time with timezone to Julian Day (UT)
+pub fn local_datetime_to_julian_day(
+    date: NaiveDate,
+    time: NaiveTime,
+    timezone: &LocalTimezone,
+    ambiguous: AmbiguousTimePolicy,
+) -> Result<f64, String> {
+    let local_dt = resolve_local_datetime(date, time, timezone, ambiguous)?;
+    Ok(datetime_to_julian_day(local_dt.naive_utc()))
+}
 
-    Ok(datetime_to_julian_day(utc_datetime))
+/// Calculate position of a planet at a given Julian Day in the given zodiac
+pub fn calc_planet_position(
+    planet: Planet,
+    julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<PlanetaryPosition, String> {
+    calc_body_position(Body::Planet(planet), julian_day, zodiac)
 }
 
-/// Calculate position of a planet at a given Julian Day
-pub fn calc_planet_position(planet: Planet, julian_day: f64) -> Result<PlanetaryPosition, String> {
+/// Calculate position of any body (including asteroids) at a given Julian Day in the given zodiac
+pub fn calc_body_position(
+    body: Body,
+    julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<PlanetaryPosition, String> {
     init_ephemeris();
 
     let mut xx: [f64; 6] = [0.0; 6];
     let mut serr: [i8; 256] = [0; 256];
 
-    let iflg = SEFLG_SPEED | SEFLG_SWIEPH;
+    let ret = with_zodiac(zodiac, |zodiac_flags| unsafe {
+        libswisseph_sys::swe_calc_ut(
+            julian_day,
+            body.swe_id(),
+            SEFLG_SPEED | SEFLG_SWIEPH | zodiac_flags,
+            xx.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    });
 
+    if ret < 0 {
+        let message = swe_error_message(&serr);
+        return Err(match missing_ephemeris_file(&message) {
+            Some(file) => format!(
+                "{} needs the Swiss Ephemeris file '{}', which was not found in {}. \
+                 Download it and set {} (or pass --ephe-path) to its directory",
+                body,
+                file,
+                ephemeris_path().map_or("the default search path".to_string(), |p| format!(
+                    "'{}'",
+                    p
+                )),
+                EPHEMERIS_PATH_ENV,
+            ),
+            None => format!("Swiss Ephemeris error: {}", message),
+        });
+    }
+
+    // Equatorial coordinates don't depend on the zodiac (Swiss Ephemeris caches the
+    // position, so this second call is cheap)
+    let mut eq: [f64; 6] = [0.0; 6];
     let ret = unsafe {
         libswisseph_sys::swe_calc_ut(
             julian_day,
-            planet.swe_id(),
-            iflg,
-            xx.as_mut_ptr(),
+            body.swe_id(),
+            SEFLG_SPEED | SEFLG_SWIEPH | SEFLG_EQUATORIAL,
+            eq.as_mut_ptr(),
             serr.as_mut_ptr(),
         )
     };
 
     if ret < 0 {
-        let error_msg = unsafe {
-            let c_str = std::ffi::CStr::from_ptr(serr.as_ptr());
-            c_str.to_string_lossy().to_string()
-        };
-        return Err(format!("Swiss Ephemeris error: {}", error_msg));
+        return Err(format!(
+            "Swiss Ephemeris error: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    // The South Node is the True Node's opposite point
+    if body == Body::Planet(Planet::SouthNode) {
+        xx[0] = (xx[0] + 180.0).rem_euclid(360.0);
+        xx[1] = -xx[1];
+        xx[4] = -xx[4];
+        eq[0] = (eq[0] + 180.0).rem_euclid(360.0);
+        eq[1] = -eq[1];
+        eq[4] = -eq[4];
     }
 
     let speed_longitude = xx[3];
-    let is_retrograde = planet.can_retrograde() && speed_longitude < 0.0;
+    let is_retrograde = body.can_retrograde() && speed_longitude < 0.0;
 
     Ok(PlanetaryPosition {
         longitude: xx[0],
@@ -130,15 +463,69 @@ pub fn calc_planet_position(planet: Planet, julian_day: f64) -> Result<Planetary
         speed_latitude: xx[4],
         speed_distance: xx[5],
         is_retrograde,
+        right_ascension: eq[0],
+        declination: eq[1],
+        speed_declination: eq[4],
     })
 }
 
+/// True obliquity of the ecliptic at a given Julian Day, which is also the Sun's
+/// greatest declination (the limit for out-of-bounds planets)
+pub fn calc_obliquity(julian_day: f64) -> Result<f64, String> {
+    init_ephemeris();
+
+    let mut xx: [f64; 6] = [0.0; 6];
+    let mut serr: [i8; 256] = [0; 256];
+
+    let ret = unsafe {
+        libswisseph_sys::swe_calc_ut(
+            julian_day,
+            SE_ECL_NUT,
+            0,
+            xx.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+
+    if ret < 0 {
+        return Err(format!(
+            "Swiss Ephemeris error: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    Ok(xx[0])
+}
+
+/// Name of the ephemeris file a Swiss Ephemeris error reports as missing
+/// (e.g., "SwissEph file 'seas_18.se1' not found in PATH '...'")
+fn missing_ephemeris_file(message: &str) -> Option<&str> {
+    if !message.contains("not found") {
+        return None;
+    }
+    let start = message.find('\'')? + 1;
+    let end = start + message[start..].find('\'')?;
+    Some(&message[start..end])
+}
+
 /// Calculate positions for all planets at a given Julian Day
-pub fn calc_all_planets(julian_day: f64) -> Result<Vec<(Planet, PlanetaryPosition)>, String> {
+pub fn calc_all_planets(
+    julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<Vec<(Planet, PlanetaryPosition)>, String> {
+    calc_planets(Planet::all(), julian_day, zodiac)
+}
+
+/// Calculate positions for the given planets at a given Julian Day
+pub fn calc_planets(
+    planets: &[Planet],
+    julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<Vec<(Planet, PlanetaryPosition)>, String> {
     let mut positions = Vec::new();
 
-    for planet in Planet::all() {
-        let position = calc_planet_position(*planet, julian_day)?;
+    for planet in planets {
+        let position = calc_planet_position(*planet, julian_day, zodiac)?;
         positions.push((*planet, position));
     }
 
@@ -146,56 +533,98 @@ pub fn calc_all_planets(julian_day: f64) -> Result<Vec<(Planet, PlanetaryPositio
 }
 
 /// Find the date when a planet enters a new sign (searching forward)
+/// A station between two samples splits the step into stretches of one-way motion, so a
+/// sign entered and left again near a station is still found.
 pub fn find_next_sign_ingress(
     planet: Planet,
     start_julian_day: f64,
     max_days: i32,
+    zodiac: ZodiacMode,
 ) -> Result<Option<(f64, ZodiacSign)>, String> {
-    let start_pos = calc_planet_position(planet, start_julian_day)?;
+    let start_pos = calc_planet_position(planet, start_julian_day, zodiac)?;
     let start_sign = ZodiacSign::from_longitude(start_pos.longitude);
+    let sign_at = |jd: f64| -> Result<ZodiacSign, String> {
+        Ok(ZodiacSign::from_longitude(
+            calc_planet_position(planet, jd, zodiac)?.longitude,
+        ))
+    };
 
     // Step size depends on planet speed (faster planets need smaller steps)
     let step = match planet {
         Planet::Moon => 0.5, // Moon moves ~13° per day
         Planet::Sun | Planet::Mercury | Planet::Venus => 1.0,
+        // The True Node and the osculating apogee change direction every week or two
+        Planet::NorthNode | Planet::SouthNode | Planet::TrueLilith => 1.0,
         Planet::Mars => 2.0,
         _ => 5.0, // Outer planets move slowly
     };
 
-    let mut jd = start_julian_day;
     let end_jd = start_julian_day + max_days as f64;
+    let mut prev_jd = start_julian_day;
+    let mut prev_speed = start_pos.speed_longitude;
 
-    while jd < end_jd {
-        let pos = calc_planet_position(planet, jd)?;
-        let current_sign = ZodiacSign::from_longitude(pos.longitude);
+    while prev_jd < end_jd {
+        let jd = prev_jd + step;
+        let speed = calc_planet_position(planet, jd, zodiac)?.speed_longitude;
 
-        if current_sign != start_sign {
-            // Found sign change, refine with binary search
-            let mut low = jd - step;
-            let mut high = jd;
+        let stretches = if (speed < 0.0) != (prev_speed < 0.0) {
+            let station = find_speed_change(planet, prev_jd, jd, zodiac)?;
+            vec![(prev_jd, station), (station, jd)]
+        } else {
+            vec![(prev_jd, jd)]
+        };
 
+        for (mut low, mut high) in stretches {
+            let new_sign = sign_at(high)?;
+            if new_sign == start_sign {
+                continue;
+            }
+
+            // Motion is one-way within the stretch, so the sign changes only once
             while high - low > 0.001 {
                 // ~1.4 minutes precision
                 let mid = (low + high) / 2.0;
-                let mid_pos = calc_planet_position(planet, mid)?;
-                let mid_sign = ZodiacSign::from_longitude(mid_pos.longitude);
-
-                if mid_sign == start_sign {
+                if sign_at(mid)? == start_sign {
                     low = mid;
                 } else {
                     high = mid;
                 }
             }
 
-            return Ok(Some((high, current_sign)));
+            return Ok((high <= end_jd).then_some((high, new_sign)));
         }
 
-        jd += step;
+        prev_jd = jd;
+        prev_speed = speed;
     }
 
     Ok(None)
 }
 
+/// Moment between two Julian Days at which a planet's motion in longitude changes direction
+pub(crate) fn find_speed_change(
+    planet: Planet,
+    start_julian_day: f64,
+    end_julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<f64, String> {
+    let start_retrograde =
+        calc_planet_position(planet, start_julian_day, zodiac)?.speed_longitude < 0.0;
+    let mut low = start_julian_day;
+    let mut high = end_julian_day;
+
+    while high - low > 0.0005 {
+        let mid = (low + high) / 2.0;
+        if (calc_planet_position(planet, mid, zodiac)?.speed_longitude < 0.0) == start_retrograde {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(high)
+}
+
 /// Find the date when a planet turns retrograde or direct
 pub fn find_next_station(
     planet: Planet,
@@ -206,7 +635,7 @@ pub fn find_next_station(
         return Ok(None);
     }
 
-    let start_pos = calc_planet_position(planet, start_julian_day)?;
+    let start_pos = calc_planet_position(planet, start_julian_day, ZodiacMode::Tropical)?;
     let start_retrograde = start_pos.is_retrograde;
 
     let step = 1.0; // Check daily
@@ -215,17 +644,17 @@ pub fn find_next_station(
     let end_jd = start_julian_day + max_days as f64;
 
     while jd < end_jd {
-        let pos = calc_planet_position(planet, jd)?;
+        let pos = calc_planet_position(planet, jd, ZodiacMode::Tropical)?;
 
         if pos.is_retrograde != start_retrograde {
             // Found station, refine
             let mut low = jd - step;
             let mut high = jd;
 
-            while high - low > 0.01 {
-                // ~15 minutes precision
+            while high - low > 0.0005 {
+                // ~45 seconds precision
                 let mid = (low + high) / 2.0;
-                let mid_pos = calc_planet_position(planet, mid)?;
+                let mid_pos = calc_planet_position(planet, mid, ZodiacMode::Tropical)?;
 
                 if mid_pos.is_retrograde == start_retrograde {
                     low = mid;
@@ -244,10 +673,109 @@ pub fn find_next_station(
     Ok(None)
 }
 
+/// Find every sign ingress of a planet between two Julian Days
+/// Returns (julian_day, new_sign, is_retrograde); retrograde ingresses move back into the previous sign.
+pub fn find_sign_ingresses(
+    planet: Planet,
+    start_julian_day: f64,
+    end_julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<Vec<(f64, ZodiacSign, bool)>, String> {
+    let mut ingresses = Vec::new();
+    let mut jd = start_julian_day;
+
+    while jd < end_julian_day {
+        let max_days = (end_julian_day - jd).ceil() as i32;
+        match find_next_sign_ingress(planet, jd, max_days, zodiac)? {
+            Some((ingress_jd, sign)) if ingress_jd <= end_julian_day => {
+                let pos = calc_planet_position(planet, ingress_jd, zodiac)?;
+                ingresses.push((ingress_jd, sign, pos.is_retrograde));
+                // Continue from just inside the new sign
+                jd = ingress_jd + 0.0001;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(ingresses)
+}
+
+/// Find every station of a planet between two Julian Days
+/// Returns (julian_day, is_turning_retrograde) for each.
+pub fn find_stations(
+    planet: Planet,
+    start_julian_day: f64,
+    end_julian_day: f64,
+) -> Result<Vec<(f64, bool)>, String> {
+    let mut stations = Vec::new();
+    let mut jd = start_julian_day;
+
+    while jd < end_julian_day {
+        let max_days = (end_julian_day - jd).ceil() as i32;
+        match find_next_station(planet, jd, max_days)? {
+            Some((station_jd, turning_retrograde)) if station_jd <= end_julian_day => {
+                stations.push((station_jd, turning_retrograde));
+                // Continue from just past the station, in the new direction
+                jd = station_jd + 0.001;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(stations)
+}
+
+/// Find the previous station (searching backward)
+/// Returns (julian_day, turned_retrograde) for the most recent change of direction.
+pub fn find_previous_station(
+    planet: Planet,
+    start_julian_day: f64,
+    max_days: i32,
+) -> Result<Option<(f64, bool)>, String> {
+    if !planet.can_retrograde() {
+        return Ok(None);
+    }
+
+    let start_pos = calc_planet_position(planet, start_julian_day, ZodiacMode::Tropical)?;
+    let start_retrograde = start_pos.is_retrograde;
+
+    let step = 1.0;
+
+    let mut jd = start_julian_day;
+    let end_jd = start_julian_day - max_days as f64;
+
+    while jd > end_jd {
+        jd -= step;
+        let pos = calc_planet_position(planet, jd, ZodiacMode::Tropical)?;
+
+        if pos.is_retrograde != start_retrograde {
+            // Station lies within the last step, refine
+            let mut low = jd;
+            let mut high = jd + step;
+
+            while high - low > 0.0005 {
+                let mid = (low + high) / 2.0;
+                let mid_pos = calc_planet_position(planet, mid, ZodiacMode::Tropical)?;
+
+                if mid_pos.is_retrograde == start_retrograde {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+
+            // The planet has moved in its current direction since this station
+            return Ok(Some((high, start_retrograde)));
+        }
+    }
+
+    Ok(None)
+}
+
 /// Calculate the Sun-Moon angle (for lunar phases)
 pub fn calc_sun_moon_angle(julian_day: f64) -> Result<f64, String> {
-    let sun = calc_planet_position(Planet::Sun, julian_day)?;
-    let moon = calc_planet_position(Planet::Moon, julian_day)?;
+    let sun = calc_planet_position(Planet::Sun, julian_day, ZodiacMode::Tropical)?;
+    let moon = calc_planet_position(Planet::Moon, julian_day, ZodiacMode::Tropical)?;
 
     // Moon longitude - Sun longitude, normalized to 0-360
     let angle = (moon.longitude - sun.longitude).rem_euclid(360.0);
@@ -264,7 +792,45 @@ pub fn find_next_full_moon(start_julian_day: f64, max_days: i32) -> Result<Optio
     find_next_lunar_phase(start_julian_day, 180.0, max_days)
 }
 
+/// Find the previous new moon (searching backward)
+pub fn find_previous_new_moon(start_julian_day: f64, max_days: i32) -> Result<Option<f64>, String> {
+    find_previous_lunar_phase(start_julian_day, 0.0, max_days)
+}
+
+/// Find the previous full moon (searching backward)
+pub fn find_previous_full_moon(
+    start_julian_day: f64,
+    max_days: i32,
+) -> Result<Option<f64>, String> {
+    find_previous_lunar_phase(start_julian_day, 180.0, max_days)
+}
+
+/// Signed distance (-180..180) of an angle past a target angle
+pub(crate) fn angle_past(angle: f64, target: f64) -> f64 {
+    (angle - target + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Conjunction or opposition between two longitudes, for points that only count when
+/// they fall on each other (fixed stars, eclipses). Returns the aspect and its orb.
+pub fn conjunction_or_opposition(
+    longitude1: f64,
+    longitude2: f64,
+    orb: f64,
+    include_oppositions: bool,
+) -> Option<(AspectType, f64)> {
+    let separation = (longitude2 - longitude1).rem_euclid(360.0);
+    let distance = separation.min(360.0 - separation);
+    if distance <= orb {
+        Some((AspectType::Conjunction, distance))
+    } else if include_oppositions && 180.0 - distance <= orb {
+        Some((AspectType::Opposition, 180.0 - distance))
+    } else {
+        None
+    }
+}
+
 /// Find the next occurrence of a specific lunar phase angle
+/// The Sun-Moon angle only ever increases, so a crossing is a sign change of the distance past the target.
 fn find_next_lunar_phase(
     start_julian_day: f64,
     target_angle: f64,
@@ -281,32 +847,19 @@ fn find_next_lunar_phase(
         let current_angle = calc_sun_moon_angle(jd)?;
 
         // Check if we crossed the target angle
-        let crossed = if target_angle < 10.0 || target_angle > 350.0 {
-            // Handle wrap-around for new moon (0°)
-            (prev_angle > 350.0 && current_angle < 10.0)
-                || (prev_angle < target_angle + 10.0
-                    && current_angle >= target_angle
-                    && current_angle < target_angle + 20.0)
-        } else {
-            prev_angle < target_angle && current_angle >= target_angle
-        };
-
-        if crossed {
+        if angle_past(prev_angle, target_angle) < 0.0
+            && angle_past(current_angle, target_angle) >= 0.0
+        {
             // Refine with binary search
             let mut low = jd - step;
             let mut high = jd;
 
-            while high - low > 0.001 {
+            while high - low > 0.0001 {
+                // ~9 seconds precision
                 let mid = (low + high) / 2.0;
                 let mid_angle = calc_sun_moon_angle(mid)?;
 
-                let before_target = if target_angle < 10.0 {
-                    mid_angle > 180.0 // Before new moon means angle > 180
-                } else {
-                    mid_angle < target_angle
-                };
-
-                if before_target {
+                if angle_past(mid_angle, target_angle) < 0.0 {
                     low = mid;
                 } else {
                     high = mid;
@@ -322,6 +875,35 @@ fn find_next_lunar_phase(
     Ok(None)
 }
 
+/// Find the previous occurrence of a specific lunar phase angle (searching backward)
+fn find_previous_lunar_phase(
+    start_julian_day: f64,
+    target_angle: f64,
+    max_days: i32,
+) -> Result<Option<f64>, String> {
+    let step = 0.5;
+    let mut jd = start_julian_day;
+    let end_jd = start_julian_day - max_days as f64;
+
+    let mut later_angle = calc_sun_moon_angle(jd)?;
+
+    while jd > end_jd {
+        jd -= step;
+        let earlier_angle = calc_sun_moon_angle(jd)?;
+
+        if angle_past(earlier_angle, target_angle) < 0.0
+            && angle_past(later_angle, target_angle) >= 0.0
+        {
+            // Refine within the bracketing step
+            return find_next_lunar_phase(jd, target_angle, 1);
+        }
+
+        later_angle = earlier_angle;
+    }
+
+    Ok(None)
+}
+
 /// Convert Julian Day back to NaiveDate
 pub fn julian_day_to_date(julian_day: f64) -> NaiveDate {
     let mut year: i32 = 0;
@@ -362,16 +944,27 @@ pub fn julian_day_to_datetime(julian_day: f64) -> NaiveDateTime {
         );
     }
 
-    let hours = hour.floor() as u32;
-    let minutes = ((hour - hours as f64) * 60.0).floor() as u32;
-    let seconds = (((hour - hours as f64) * 60.0 - minutes as f64) * 60.0).floor() as u32;
-
     let date = NaiveDate::from_ymd_opt(year, month as u32, day as u32)
         .unwrap_or_else(|| NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
-    let time = NaiveTime::from_hms_opt(hours, minutes, seconds)
-        .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
 
-    NaiveDateTime::new(date, time)
+    // Round to the nearest second so exact hours don't come out as hh:59:59
+    let seconds = (hour * 3600.0).round() as i64;
+    NaiveDateTime::new(date, NaiveTime::MIN) + chrono::Duration::seconds(seconds)
+}
+
+/// Format a Julian Day as an ISO-8601 UTC timestamp (e.g., "2024-12-18T14:32:05Z")
+pub fn julian_day_to_timestamp(julian_day: f64) -> String {
+    julian_day_to_datetime(julian_day)
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+/// Format a Julian Day as an ISO-8601 timestamp in the given timezone (e.g., "2024-12-18T09:32:05-05:00")
+pub fn julian_day_to_local_timestamp(julian_day: f64, timezone: &LocalTimezone) -> String {
+    timezone
+        .from_utc(julian_day_to_datetime(julian_day))
+        .format("%Y-%m-%dT%H:%M:%S%:z")
+        .to_string()
 }
 
 #[cfg(test)]
@@ -390,12 +983,179 @@ mod tests {
     fn test_planet_calculation() {
         init_ephemeris();
         let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
-        let sun = calc_planet_position(Planet::Sun, jd).unwrap();
+        let sun = calc_planet_position(Planet::Sun, jd, ZodiacMode::Tropical).unwrap();
 
         // Sun should be around 280° (Capricorn) on Jan 1, 2000
         assert!(sun.longitude > 270.0 && sun.longitude < 290.0);
     }
 
+    #[test]
+    fn test_solstice_declination() {
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2000, 6, 21).unwrap());
+        let sun = calc_planet_position(Planet::Sun, jd, ZodiacMode::Tropical).unwrap();
+        let obliquity = calc_obliquity(jd).unwrap();
+
+        // At the June solstice the Sun reaches its greatest declination, the obliquity
+        assert!((obliquity - 23.44).abs() < 0.01);
+        assert!((sun.declination - obliquity).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_south_node_opposes_north_node() {
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+        let north = calc_planet_position(Planet::NorthNode, jd, ZodiacMode::Tropical).unwrap();
+        let south = calc_planet_position(Planet::SouthNode, jd, ZodiacMode::Tropical).unwrap();
+
+        assert!((angle_past(south.longitude - north.longitude, 180.0)).abs() < 1e-9);
+        assert_eq!(south.speed_longitude, north.speed_longitude);
+        assert!(south.is_retrograde == north.is_retrograde);
+    }
+
+    #[test]
+    fn test_sidereal_offset() {
+        init_ephemeris();
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+        let tropical = calc_planet_position(Planet::Sun, jd, ZodiacMode::Tropical).unwrap();
+        let sidereal =
+            calc_planet_position(Planet::Sun, jd, ZodiacMode::Sidereal(Ayanamsa::Lahiri)).unwrap();
+        let ayanamsa = calc_ayanamsa(Ayanamsa::Lahiri, jd).unwrap();
+
+        // Lahiri ayanamsa is about 23°51' at J2000
+        assert!((ayanamsa - 23.85).abs() < 0.05);
+        let offset = (tropical.longitude - sidereal.longitude).rem_euclid(360.0);
+        assert!((offset - ayanamsa).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_query_time_in_dst_gap() {
+        // Chile skipped from 00:00 to 01:00 on 2022-09-11, so that day starts at 01:00 (-03:00)
+        let santiago = LocalTimezone::parse("America/Santiago", None).unwrap();
+        let date = NaiveDate::from_ymd_opt(2022, 9, 11).unwrap();
+        assert!(resolve_local_datetime(
+            date,
+            NaiveTime::MIN,
+            &santiago,
+            AmbiguousTimePolicy::Earlier
+        )
+        .is_err());
+        let midnight = resolve_query_datetime(date, NaiveTime::MIN, &santiago).unwrap();
+        assert_eq!(midnight.to_rfc3339(), "2022-09-11T01:00:00-03:00");
+
+        // Times outside a gap resolve as usual, and a repeated hour uses its first occurrence
+        let new_york = LocalTimezone::parse("America/New_York", None).unwrap();
+        let gap = resolve_query_datetime(
+            NaiveDate::from_ymd_opt(2024, 3, 10).unwrap(),
+            NaiveTime::from_hms_opt(2, 30, 15).unwrap(),
+            &new_york,
+        )
+        .unwrap();
+        assert_eq!(gap.to_rfc3339(), "2024-03-10T03:00:00-04:00");
+        let repeated = resolve_query_datetime(
+            NaiveDate::from_ymd_opt(2024, 11, 3).unwrap(),
+            NaiveTime::from_hms_opt(1, 30, 0).unwrap(),
+            &new_york,
+        )
+        .unwrap();
+        assert_eq!(repeated.offset().local_minus_utc(), -4 * 3600);
+    }
+
+    #[test]
+    fn test_local_time_resolution() {
+        let new_york = LocalTimezone::parse("America/New_York", None).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap();
+        let time = NaiveTime::from_hms_opt(1, 30, 0).unwrap();
+
+        // 01:30 happens twice when clocks fall back: EDT first, then EST
+        assert!(
+            resolve_local_datetime(date, time, &new_york, AmbiguousTimePolicy::Reject).is_err()
+        );
+        let earlier =
+            resolve_local_datetime(date, time, &new_york, AmbiguousTimePolicy::Earlier).unwrap();
+        let later =
+            resolve_local_datetime(date, time, &new_york, AmbiguousTimePolicy::Later).unwrap();
+        assert_eq!(earlier.offset().local_minus_utc(), -4 * 3600);
+        assert_eq!((later - earlier).num_hours(), 1);
+
+        // 02:30 is skipped when clocks spring forward
+        let spring = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let gap = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        assert!(
+            resolve_local_datetime(spring, gap, &new_york, AmbiguousTimePolicy::Earlier).is_err()
+        );
+
+        let india = LocalTimezone::parse("+05:30", None).unwrap();
+        assert_eq!(
+            india,
+            LocalTimezone::Fixed(FixedOffset::east_opt(19800).unwrap())
+        );
+
+        // Local mean time at 75°W is five hours behind Greenwich
+        let lmt = LocalTimezone::parse("LMT", Some(-75.0)).unwrap();
+        assert_eq!(
+            lmt,
+            LocalTimezone::Fixed(FixedOffset::west_opt(5 * 3600).unwrap())
+        );
+        assert!(LocalTimezone::parse("LMT", None).is_err());
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let datetime = |y, m, d, h, min, sec| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, sec)
+                .unwrap()
+        };
+
+        // Whole seconds and exact hours survive the round trip through a Julian Day
+        let jd = datetime_to_julian_day(datetime(2024, 12, 18, 14, 32, 5));
+        assert_eq!(julian_day_to_timestamp(jd), "2024-12-18T14:32:05Z");
+        assert_eq!(
+            julian_day_to_datetime(jd),
+            datetime(2024, 12, 18, 14, 32, 5)
+        );
+        let noon = datetime_to_julian_day(datetime(2024, 3, 10, 12, 0, 0));
+        assert_eq!(julian_day_to_timestamp(noon), "2024-03-10T12:00:00Z");
+
+        // Fractions of a second round to the nearest second, across the end of the year
+        let midnight = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        assert_eq!(
+            julian_day_to_timestamp(midnight + 86399.6 / 86400.0),
+            "2025-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            julian_day_to_timestamp(midnight + 86399.4 / 86400.0),
+            "2024-12-31T23:59:59Z"
+        );
+
+        // Local timestamps carry the offset in effect at that moment
+        let new_york = LocalTimezone::parse("America/New_York", None).unwrap();
+        let local = |dt| julian_day_to_local_timestamp(datetime_to_julian_day(dt), &new_york);
+        assert_eq!(
+            local(datetime(2024, 7, 1, 12, 0, 0)),
+            "2024-07-01T08:00:00-04:00"
+        );
+        assert_eq!(
+            local(datetime(2024, 1, 15, 12, 0, 0)),
+            "2024-01-15T07:00:00-05:00"
+        );
+        // 01:30 happens twice when clocks fall back on 2024-11-03
+        assert_eq!(
+            local(datetime(2024, 11, 3, 5, 30, 0)),
+            "2024-11-03T01:30:00-04:00"
+        );
+        assert_eq!(
+            local(datetime(2024, 11, 3, 6, 30, 0)),
+            "2024-11-03T01:30:00-05:00"
+        );
+
+        let india = LocalTimezone::parse("+05:30", None).unwrap();
+        assert_eq!(
+            julian_day_to_local_timestamp(jd, &india),
+            "2024-12-18T20:02:05+05:30"
+        );
+    }
+
     #[test]
     fn test_zodiac_sign() {
         let sign = ZodiacSign::from_longitude(280.0);
@@ -404,4 +1164,82 @@ mod tests {
         let sign = ZodiacSign::from_longitude(45.0);
         assert_eq!(sign, ZodiacSign::Taurus);
     }
+
+    #[test]
+    fn test_conjunction_or_opposition_across_aries_point() {
+        // Separations wrap around 0° Aries; oppositions only count when asked for
+        assert_eq!(
+            conjunction_or_opposition(0.3, 359.5, 1.0, true).map(|(a, _)| a),
+            Some(AspectType::Conjunction)
+        );
+        assert_eq!(
+            conjunction_or_opposition(14.3, 194.2, 1.0, true).map(|(a, _)| a),
+            Some(AspectType::Opposition)
+        );
+        assert!(conjunction_or_opposition(14.3, 194.2, 1.0, false).is_none());
+        assert!(conjunction_or_opposition(14.3, 16.0, 1.0, true).is_none());
+    }
+
+    #[test]
+    fn test_missing_ephemeris_file() {
+        let message = "SwissEph file 'seas_18.se1' not found in PATH '/opt/ephe'";
+        assert_eq!(missing_ephemeris_file(message), Some("seas_18.se1"));
+        assert_eq!(missing_ephemeris_file("illegal planet number 99."), None);
+        assert_eq!(missing_ephemeris_file("file not found"), None);
+    }
+
+    #[test]
+    fn test_sign_ingresses_through_pluto_retrograde() {
+        // Pluto moved between Capricorn and Aquarius five times in 2023-2024
+        let start = date_to_julian_day(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+        let end = date_to_julian_day(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        let ingresses =
+            find_sign_ingresses(Planet::Pluto, start, end, ZodiacMode::Tropical).unwrap();
+
+        let summary: Vec<(String, ZodiacSign, bool)> = ingresses
+            .iter()
+            .map(|(jd, sign, retrograde)| {
+                (
+                    julian_day_to_timestamp(*jd)[..10].to_string(),
+                    *sign,
+                    *retrograde,
+                )
+            })
+            .collect();
+        let expected = [
+            ("2023-03-23", ZodiacSign::Aquarius, false),
+            ("2023-06-11", ZodiacSign::Capricorn, true),
+            ("2024-01-21", ZodiacSign::Aquarius, false),
+            ("2024-09-01", ZodiacSign::Capricorn, true),
+            ("2024-11-19", ZodiacSign::Aquarius, false),
+        ];
+        assert_eq!(summary.len(), expected.len());
+        for ((date, sign, retrograde), (expected_date, expected_sign, expected_retrograde)) in
+            summary.iter().zip(expected)
+        {
+            assert_eq!(
+                (date.as_str(), *sign, *retrograde),
+                (expected_date, expected_sign, expected_retrograde)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_ingress_around_a_station() {
+        // Mercury stationed 0.0024° into Aquarius on 1970-01-04, leaving and re-entering
+        // Capricorn within a single day
+        let start = date_to_julian_day(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+        let end = date_to_julian_day(NaiveDate::from_ymd_opt(1970, 1, 8).unwrap());
+        let ingresses =
+            find_sign_ingresses(Planet::Mercury, start, end, ZodiacMode::Tropical).unwrap();
+        let signs: Vec<(ZodiacSign, bool)> = ingresses
+            .iter()
+            .map(|(_, sign, retrograde)| (*sign, *retrograde))
+            .collect();
+        assert_eq!(
+            signs,
+            vec![(ZodiacSign::Aquarius, false), (ZodiacSign::Capricorn, true)]
+        );
+        assert!(ingresses[1].0 - ingresses[0].0 < 1.0);
+    }
 }
@@ -0,0 +1,255 @@
+//! Geographic path of a solar eclipse
+//!
+//! Swiss Ephemeris gives the central line (`swe_sol_eclipse_where`) but not the path
+//! limits. At each moment the limits are found by walking away from the shadow axis,
+//! at right angles to its track, until `swe_sol_eclipse_how` no longer sees a total or
+//! annular (umbral limits) or any (penumbral limits) eclipse. The penumbral limits are
+//! also cut off where the Sun is below the horizon.
+
+use crate::models::EclipseKind;
+
+use super::calculator::{init_ephemeris, swe_error_message};
+use super::eclipses::{eclipse_how, Eclipse};
+
+const SEFLG_SWIEPH: i32 = 2;
+const SE_ECL_CENTRAL: i32 = 1;
+const SE_ECL_TOTAL: i32 = 4;
+const SE_ECL_ANNULAR: i32 = 8;
+const SE_ECL_ANNULAR_TOTAL: i32 = 32;
+
+/// Mean Earth radius in km
+const EARTH_RADIUS: f64 = 6371.0;
+
+/// One minute as a fraction of a day
+const MINUTE: f64 = 1.0 / 1440.0;
+
+/// A geographic position as (longitude, latitude) in degrees, east and north positive
+pub type GeoPoint = (f64, f64);
+
+/// Lines tracing a solar eclipse on the Earth's surface, in time order
+#[derive(Debug, Clone, Default)]
+pub struct EclipsePath {
+    /// Where the eclipse is greatest
+    pub greatest_eclipse: Option<GeoPoint>,
+    /// Path of the shadow axis (central eclipses only)
+    pub central_line: Vec<GeoPoint>,
+    /// Northern and southern limits of the total or annular phase
+    pub umbral_limits: [Vec<GeoPoint>; 2],
+    /// Northern and southern limits of the partial phase
+    pub penumbral_limits: [Vec<GeoPoint>; 2],
+}
+
+/// Which part of the shadow a limit bounds
+#[derive(Clone, Copy)]
+enum Shadow {
+    Umbra,
+    Penumbra,
+}
+
+impl Shadow {
+    /// Walking step and farthest distance from the axis, in km
+    fn search(self) -> (f64, f64) {
+        match self {
+            Shadow::Umbra => (10.0, 2000.0),
+            Shadow::Penumbra => (100.0, 10000.0),
+        }
+    }
+
+    /// Minutes between samples along the track
+    fn sampling(self) -> f64 {
+        match self {
+            Shadow::Umbra => 2.0,
+            Shadow::Penumbra => 5.0,
+        }
+    }
+
+    fn covers(self, flags: i32) -> bool {
+        match self {
+            Shadow::Umbra => flags & (SE_ECL_TOTAL | SE_ECL_ANNULAR | SE_ECL_ANNULAR_TOTAL) != 0,
+            Shadow::Penumbra => flags > 0,
+        }
+    }
+}
+
+/// Trace the central line and path limits of a solar eclipse
+pub fn find_eclipse_path(eclipse: &Eclipse) -> Result<EclipsePath, String> {
+    if eclipse.kind != EclipseKind::Solar {
+        return Err("Only solar eclipses have a path on the Earth's surface".to_string());
+    }
+    init_ephemeris();
+
+    let mut path = EclipsePath {
+        greatest_eclipse: Some(shadow_axis(eclipse.maximum)?.0),
+        ..EclipsePath::default()
+    };
+
+    if let (Some(begin), Some(end)) = (eclipse.totality_begin, eclipse.totality_end) {
+        for jd in sample_times(begin, end, 1.0) {
+            let (point, flags) = shadow_axis(jd)?;
+            if flags & SE_ECL_CENTRAL != 0 {
+                path.central_line.push(point);
+            }
+        }
+        path.umbral_limits = trace_limits(Shadow::Umbra, begin, end)?;
+    }
+
+    if let (Some(begin), Some(end)) = (eclipse.begin, eclipse.end) {
+        path.penumbral_limits = trace_limits(Shadow::Penumbra, begin, end)?;
+    }
+
+    Ok(path)
+}
+
+/// Evenly spaced times from start to end inclusive, the given number of minutes apart
+fn sample_times(start: f64, end: f64, minutes: f64) -> Vec<f64> {
+    let steps = ((end - start) / (minutes * MINUTE)).ceil().max(1.0) as usize;
+    (0..=steps)
+        .map(|i| start + (end - start) * i as f64 / steps as f64)
+        .collect()
+}
+
+/// Where the shadow axis meets the Earth (or comes closest to it), with the eclipse flags
+fn shadow_axis(julian_day: f64) -> Result<(GeoPoint, i32), String> {
+    let mut geopos: [f64; 10] = [0.0; 10];
+    let mut attr: [f64; 20] = [0.0; 20];
+    let mut serr: [i8; 256] = [0; 256];
+    let flags = unsafe {
+        libswisseph_sys::swe_sol_eclipse_where(
+            julian_day,
+            SEFLG_SWIEPH,
+            geopos.as_mut_ptr(),
+            attr.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if flags < 0 {
+        return Err(format!(
+            "Eclipse path calculation failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+    Ok(((geopos[0], geopos[1]), flags))
+}
+
+/// Both limits of a shadow between two times, ordered north then south
+fn trace_limits(shadow: Shadow, start: f64, end: f64) -> Result<[Vec<GeoPoint>; 2], String> {
+    let mut north = Vec::new();
+    let mut south = Vec::new();
+
+    for jd in sample_times(start, end, shadow.sampling()) {
+        let (axis, _) = shadow_axis(jd)?;
+        let (before, _) = shadow_axis(jd - MINUTE)?;
+        let (after, _) = shadow_axis(jd + MINUTE)?;
+        let track = bearing(before, after);
+
+        let left = find_limit(shadow, jd, axis, track - 90.0)?;
+        let right = find_limit(shadow, jd, axis, track + 90.0)?;
+
+        // The track can turn back near the Earth's limb, so compare the two edges directly
+        // and only fall back to the direction of travel when one is missing
+        let (n, s) = match (left, right) {
+            (Some(l), Some(r)) if l.1 >= r.1 => (Some(l), Some(r)),
+            (Some(l), Some(r)) => (Some(r), Some(l)),
+            _ if track.rem_euclid(360.0) < 180.0 => (left, right),
+            _ => (right, left),
+        };
+        north.extend(n);
+        south.extend(s);
+    }
+
+    Ok([north, south])
+}
+
+/// Walk from the shadow axis along a bearing to the edge of the shadow at one moment
+fn find_limit(
+    shadow: Shadow,
+    julian_day: f64,
+    axis: GeoPoint,
+    direction: f64,
+) -> Result<Option<GeoPoint>, String> {
+    let covered = |distance: f64| -> Result<bool, String> {
+        let (longitude, latitude) = destination(axis, direction, distance);
+        let (flags, _) = eclipse_how(EclipseKind::Solar, julian_day, latitude, longitude)?;
+        Ok(shadow.covers(flags))
+    };
+
+    if !covered(0.0)? {
+        return Ok(None);
+    }
+
+    let (step, max_distance) = shadow.search();
+    let mut inside = 0.0;
+    let mut outside = step;
+    while covered(outside)? {
+        if outside >= max_distance {
+            return Ok(None);
+        }
+        inside = outside;
+        outside += step;
+    }
+
+    // Refine the edge to about 1 km
+    while outside - inside > 1.0 {
+        let mid = (inside + outside) / 2.0;
+        if covered(mid)? {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+
+    Ok(Some(destination(axis, direction, inside)))
+}
+
+/// Initial great-circle bearing from one point to another, in degrees from north
+fn bearing(from: GeoPoint, to: GeoPoint) -> f64 {
+    let (lon1, lat1) = (from.0.to_radians(), from.1.to_radians());
+    let (lon2, lat2) = (to.0.to_radians(), to.1.to_radians());
+    let y = (lon2 - lon1).sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * (lon2 - lon1).cos();
+    y.atan2(x).to_degrees()
+}
+
+/// Point reached by travelling a distance (km) along a great circle from a start and bearing
+fn destination(start: GeoPoint, bearing: f64, distance: f64) -> GeoPoint {
+    let (lon1, lat1) = (start.0.to_radians(), start.1.to_radians());
+    let bearing = bearing.to_radians();
+    let angle = distance / EARTH_RADIUS;
+
+    let lat2 = (lat1.sin() * angle.cos() + lat1.cos() * angle.sin() * bearing.cos()).asin();
+    let lon2 = lon1
+        + (bearing.sin() * angle.sin() * lat1.cos()).atan2(angle.cos() - lat1.sin() * lat2.sin());
+
+    (
+        (lon2.to_degrees() + 540.0).rem_euclid(360.0) - 180.0,
+        lat2.to_degrees(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::date_to_julian_day;
+    use crate::ephemeris::eclipses::find_solar_eclipse;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_path_of_2024_total_eclipse() {
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        let eclipse = find_solar_eclipse(jd, false).unwrap();
+        let path = find_eclipse_path(&eclipse).unwrap();
+
+        // Greatest eclipse near Nazas, Durango (25.3° N, 104.1° W)
+        let (longitude, latitude) = path.greatest_eclipse.unwrap();
+        assert!((longitude + 104.1).abs() < 0.5 && (latitude - 25.3).abs() < 0.5);
+
+        // The path of totality is about 200 km wide over Mexico and the United States
+        assert!(!path.central_line.is_empty());
+        let [north, south] = &path.umbral_limits;
+        assert!(!north.is_empty() && !south.is_empty());
+        assert!(
+            north.iter().map(|p| p.1).sum::<f64>() / north.len() as f64
+                > south.iter().map(|p| p.1).sum::<f64>() / south.len() as f64
+        );
+    }
+}
@@ -0,0 +1,529 @@
+//! Solar and lunar eclipses
+//!
+//! Eclipses are found with Swiss Ephemeris' global searches. Solar eclipse magnitude
+//! and saros numbers come from the point of greatest eclipse; lunar eclipses look the
+//! same from everywhere the Moon is up.
+
+use crate::models::{EclipseKind, EclipseType, Planet};
+
+use super::calculator::{init_ephemeris, swe_error_message};
+
+const SEFLG_SWIEPH: i32 = 2;
+
+// Eclipse type flags (swephexp.h)
+const SE_ECL_CENTRAL: i32 = 1;
+const SE_ECL_TOTAL: i32 = 4;
+const SE_ECL_ANNULAR: i32 = 8;
+const SE_ECL_PARTIAL: i32 = 16;
+const SE_ECL_ANNULAR_TOTAL: i32 = 32;
+const SE_ECL_PENUMBRAL: i32 = 64;
+
+/// Default orb for eclipses falling on natal points (degrees of longitude)
+pub const DEFAULT_ECLIPSE_ORB: f64 = 3.0;
+
+/// An eclipse contact seen from one place on Earth
+#[derive(Debug, Clone)]
+pub struct LocalContact {
+    /// e.g. "first_contact" (solar) or "partial_begin" (lunar)
+    pub name: &'static str,
+    pub julian_day: f64,
+    /// Apparent altitude of the eclipsed Sun or Moon, in degrees
+    pub altitude: f64,
+}
+
+/// Local circumstances of an eclipse at one place on Earth
+#[derive(Debug, Clone)]
+pub struct LocalEclipse {
+    /// Type as seen from the place (a total solar eclipse is partial outside the path)
+    pub eclipse_type: EclipseType,
+    /// Time of greatest eclipse at the place
+    pub maximum: f64,
+    /// Solar: fraction of the Sun's diameter covered. Lunar: umbral magnitude, or penumbral for penumbral eclipses.
+    pub magnitude: f64,
+    /// Fraction of the Sun's disc covered (solar eclipses only)
+    pub obscuration: Option<f64>,
+    /// Apparent altitude and azimuth (degrees from north, clockwise) at greatest eclipse
+    pub altitude: f64,
+    pub azimuth: f64,
+    /// Contacts in time order
+    pub contacts: Vec<LocalContact>,
+}
+
+/// An eclipse with its phase times as Julian Days (UT)
+#[derive(Debug, Clone)]
+pub struct Eclipse {
+    pub kind: EclipseKind,
+    pub eclipse_type: EclipseType,
+    /// Whether the shadow axis touches the Earth (solar eclipses only)
+    pub central: bool,
+    /// Time of greatest eclipse
+    pub maximum: f64,
+    /// First and last contact anywhere on Earth (penumbral contacts for lunar eclipses)
+    pub begin: Option<f64>,
+    pub end: Option<f64>,
+    /// Umbral phase of a lunar eclipse
+    pub partial_begin: Option<f64>,
+    pub partial_end: Option<f64>,
+    /// Total or annular phase
+    pub totality_begin: Option<f64>,
+    pub totality_end: Option<f64>,
+    /// Solar: fraction of the Sun's diameter covered (or the Moon/Sun diameter ratio for
+    /// total and annular eclipses). Lunar: umbral magnitude, or penumbral for penumbral eclipses.
+    pub magnitude: f64,
+    /// Penumbral magnitude (lunar eclipses only)
+    pub penumbral_magnitude: Option<f64>,
+    pub saros_series: Option<i32>,
+    pub saros_member: Option<i32>,
+}
+
+impl Eclipse {
+    /// The eclipsed body: the Sun for solar eclipses, the Moon for lunar ones
+    pub fn luminary(&self) -> Planet {
+        match self.kind {
+            EclipseKind::Solar => Planet::Sun,
+            EclipseKind::Lunar => Planet::Moon,
+        }
+    }
+}
+
+/// Swiss Ephemeris leaves times of phases that don't happen at zero
+fn phase_time(julian_day: f64) -> Option<f64> {
+    (julian_day > 0.0).then_some(julian_day)
+}
+
+/// Saros numbers, which Swiss Ephemeris marks as unknown with a large negative value
+fn saros(attr: &[f64]) -> (Option<i32>, Option<i32>) {
+    if attr[9] > 0.0 {
+        (Some(attr[9] as i32), Some(attr[10] as i32))
+    } else {
+        (None, None)
+    }
+}
+
+/// Find the next solar eclipse after a Julian Day (or the previous one, searching backward)
+pub fn find_solar_eclipse(julian_day: f64, backward: bool) -> Result<Eclipse, String> {
+    init_ephemeris();
+
+    let mut tret: [f64; 10] = [0.0; 10];
+    let mut serr: [i8; 256] = [0; 256];
+    let flags = unsafe {
+        libswisseph_sys::swe_sol_eclipse_when_glob(
+            julian_day,
+            SEFLG_SWIEPH,
+            0,
+            tret.as_mut_ptr(),
+            backward as i32,
+            serr.as_mut_ptr(),
+        )
+    };
+    if flags < 0 {
+        return Err(format!(
+            "Solar eclipse search failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    // Magnitude and saros series at the point of greatest eclipse
+    let mut geopos: [f64; 10] = [0.0; 10];
+    let mut attr: [f64; 20] = [0.0; 20];
+    let ret = unsafe {
+        libswisseph_sys::swe_sol_eclipse_where(
+            tret[0],
+            SEFLG_SWIEPH,
+            geopos.as_mut_ptr(),
+            attr.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if ret < 0 {
+        return Err(format!(
+            "Solar eclipse calculation failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    let eclipse_type = if flags & SE_ECL_ANNULAR_TOTAL != 0 {
+        EclipseType::Hybrid
+    } else if flags & SE_ECL_TOTAL != 0 {
+        EclipseType::Total
+    } else if flags & SE_ECL_ANNULAR != 0 {
+        EclipseType::Annular
+    } else {
+        EclipseType::Partial
+    };
+    let (saros_series, saros_member) = saros(&attr);
+
+    Ok(Eclipse {
+        kind: EclipseKind::Solar,
+        eclipse_type,
+        central: flags & SE_ECL_CENTRAL != 0,
+        maximum: tret[0],
+        begin: phase_time(tret[2]),
+        end: phase_time(tret[3]),
+        partial_begin: None,
+        partial_end: None,
+        totality_begin: phase_time(tret[4]),
+        totality_end: phase_time(tret[5]),
+        magnitude: attr[8],
+        penumbral_magnitude: None,
+        saros_series,
+        saros_member,
+    })
+}
+
+/// Find the next lunar eclipse after a Julian Day (or the previous one, searching backward)
+pub fn find_lunar_eclipse(julian_day: f64, backward: bool) -> Result<Eclipse, String> {
+    init_ephemeris();
+
+    let mut tret: [f64; 10] = [0.0; 10];
+    let mut serr: [i8; 256] = [0; 256];
+    let flags = unsafe {
+        libswisseph_sys::swe_lun_eclipse_when(
+            julian_day,
+            SEFLG_SWIEPH,
+            0,
+            tret.as_mut_ptr(),
+            backward as i32,
+            serr.as_mut_ptr(),
+        )
+    };
+    if flags < 0 {
+        return Err(format!(
+            "Lunar eclipse search failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    // Without a location the magnitudes are the same for every observer
+    let mut attr: [f64; 20] = [0.0; 20];
+    let ret = unsafe {
+        libswisseph_sys::swe_lun_eclipse_how(
+            tret[0],
+            SEFLG_SWIEPH,
+            std::ptr::null_mut(),
+            attr.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if ret < 0 {
+        return Err(format!(
+            "Lunar eclipse calculation failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+
+    let eclipse_type = if flags & SE_ECL_TOTAL != 0 {
+        EclipseType::Total
+    } else if flags & SE_ECL_PARTIAL != 0 {
+        EclipseType::Partial
+    } else {
+        debug_assert!(flags & SE_ECL_PENUMBRAL != 0);
+        EclipseType::Penumbral
+    };
+    let (saros_series, saros_member) = saros(&attr);
+
+    Ok(Eclipse {
+        kind: EclipseKind::Lunar,
+        eclipse_type,
+        central: false,
+        maximum: tret[0],
+        begin: phase_time(tret[6]),
+        end: phase_time(tret[7]),
+        partial_begin: phase_time(tret[2]),
+        partial_end: phase_time(tret[3]),
+        totality_begin: phase_time(tret[4]),
+        totality_end: phase_time(tret[5]),
+        magnitude: if eclipse_type == EclipseType::Penumbral {
+            attr[1]
+        } else {
+            attr[0]
+        },
+        penumbral_magnitude: Some(attr[1]),
+        saros_series,
+        saros_member,
+    })
+}
+
+/// Find the nearest eclipse of the given kind (either kind if None) after or before a Julian Day
+pub fn find_eclipse(
+    julian_day: f64,
+    kind: Option<EclipseKind>,
+    backward: bool,
+) -> Result<Eclipse, String> {
+    let solar = match kind {
+        Some(EclipseKind::Lunar) => None,
+        _ => Some(find_solar_eclipse(julian_day, backward)?),
+    };
+    let lunar = match kind {
+        Some(EclipseKind::Solar) => None,
+        _ => Some(find_lunar_eclipse(julian_day, backward)?),
+    };
+
+    let nearest = match (solar, lunar) {
+        (Some(solar), Some(lunar)) => {
+            let solar_first = (solar.maximum < lunar.maximum) != backward;
+            if solar_first {
+                solar
+            } else {
+                lunar
+            }
+        }
+        (Some(eclipse), None) | (None, Some(eclipse)) => eclipse,
+        (None, None) => unreachable!("at least one eclipse kind is searched"),
+    };
+    Ok(nearest)
+}
+
+/// Find every eclipse of the given kind (either kind if None) with its maximum between two Julian Days
+pub fn find_eclipses(
+    start_julian_day: f64,
+    end_julian_day: f64,
+    kind: Option<EclipseKind>,
+) -> Result<Vec<Eclipse>, String> {
+    let mut eclipses = Vec::new();
+    let mut jd = start_julian_day;
+
+    while jd <= end_julian_day {
+        let eclipse = find_eclipse(jd, kind, false)?;
+        if eclipse.maximum > end_julian_day {
+            break;
+        }
+        // Solar and lunar eclipses come at least two weeks apart
+        jd = eclipse.maximum + 1.0;
+        eclipses.push(eclipse);
+    }
+
+    Ok(eclipses)
+}
+
+/// Find the given number of consecutive eclipses after a Julian Day (or before it, searching backward)
+pub fn find_eclipse_sequence(
+    julian_day: f64,
+    kind: Option<EclipseKind>,
+    backward: bool,
+    count: usize,
+) -> Result<Vec<Eclipse>, String> {
+    let mut eclipses = Vec::with_capacity(count);
+    let mut jd = julian_day;
+
+    while eclipses.len() < count {
+        let eclipse = find_eclipse(jd, kind, backward)?;
+        jd = if backward {
+            eclipse.maximum - 1.0
+        } else {
+            eclipse.maximum + 1.0
+        };
+        eclipses.push(eclipse);
+    }
+
+    Ok(eclipses)
+}
+
+/// The prenatal eclipses: the last solar and the last lunar eclipse before a birth
+pub fn prenatal_eclipses(birth_julian_day: f64) -> Result<[Eclipse; 2], String> {
+    Ok([
+        find_solar_eclipse(birth_julian_day, true)?,
+        find_lunar_eclipse(birth_julian_day, true)?,
+    ])
+}
+
+/// Local circumstances of an eclipse at a geographic position
+/// Returns None when the eclipse cannot be seen there (the Sun or Moon stays below the horizon).
+pub fn local_eclipse(
+    eclipse: &Eclipse,
+    latitude: f64,
+    longitude: f64,
+) -> Result<Option<LocalEclipse>, String> {
+    init_ephemeris();
+
+    let mut geopos: [f64; 3] = [longitude, latitude, 0.0];
+    let mut tret: [f64; 10] = [0.0; 10];
+    let mut attr: [f64; 20] = [0.0; 20];
+    let mut serr: [i8; 256] = [0; 256];
+
+    // The search finds the next eclipse visible from the place; anything later means this one isn't
+    let search_start = eclipse.begin.unwrap_or(eclipse.maximum) - 0.5;
+    let flags = unsafe {
+        match eclipse.kind {
+            EclipseKind::Solar => libswisseph_sys::swe_sol_eclipse_when_loc(
+                search_start,
+                SEFLG_SWIEPH,
+                geopos.as_mut_ptr(),
+                tret.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                0,
+                serr.as_mut_ptr(),
+            ),
+            EclipseKind::Lunar => libswisseph_sys::swe_lun_eclipse_when_loc(
+                search_start,
+                SEFLG_SWIEPH,
+                geopos.as_mut_ptr(),
+                tret.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                0,
+                serr.as_mut_ptr(),
+            ),
+        }
+    };
+    if flags < 0 {
+        return Err(format!(
+            "Local eclipse calculation failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+    if (tret[0] - eclipse.maximum).abs() > 0.5 {
+        return Ok(None);
+    }
+
+    let contact_times: &[(&'static str, usize)] = match eclipse.kind {
+        EclipseKind::Solar => &[
+            ("first_contact", 1),
+            ("second_contact", 2),
+            ("third_contact", 3),
+            ("fourth_contact", 4),
+        ],
+        EclipseKind::Lunar => &[
+            ("penumbral_begin", 6),
+            ("partial_begin", 2),
+            ("total_begin", 4),
+            ("total_end", 5),
+            ("partial_end", 3),
+            ("penumbral_end", 7),
+        ],
+    };
+    let mut contacts = Vec::new();
+    for &(name, index) in contact_times {
+        if let Some(julian_day) = phase_time(tret[index]) {
+            let altitude = eclipse_how(eclipse.kind, julian_day, latitude, longitude)?.1[6];
+            contacts.push(LocalContact {
+                name,
+                julian_day,
+                altitude,
+            });
+        }
+    }
+
+    let (local_flags, attr) = eclipse_how(eclipse.kind, tret[0], latitude, longitude)?;
+    let eclipse_type = match eclipse.kind {
+        EclipseKind::Solar => {
+            // The Sun may be down at greatest eclipse, so fall back to the search's verdict
+            let flags = if local_flags > 0 { local_flags } else { flags };
+            if flags & SE_ECL_TOTAL != 0 {
+                EclipseType::Total
+            } else if flags & SE_ECL_ANNULAR != 0 {
+                EclipseType::Annular
+            } else {
+                EclipseType::Partial
+            }
+        }
+        EclipseKind::Lunar => eclipse.eclipse_type,
+    };
+    let (magnitude, obscuration) = match eclipse.kind {
+        EclipseKind::Solar => (attr[0], Some(attr[2])),
+        EclipseKind::Lunar if eclipse_type == EclipseType::Penumbral => (attr[1], None),
+        EclipseKind::Lunar => (attr[0], None),
+    };
+
+    Ok(Some(LocalEclipse {
+        eclipse_type,
+        maximum: tret[0],
+        magnitude,
+        obscuration,
+        altitude: attr[6],
+        // Swiss Ephemeris measures azimuth from the south
+        azimuth: (attr[4] + 180.0).rem_euclid(360.0),
+        contacts,
+    }))
+}
+
+/// Eclipse attributes at a moment and place (swe_sol_eclipse_how / swe_lun_eclipse_how)
+/// Returns the local eclipse flags (0 when nothing is visible) and the attributes.
+pub(crate) fn eclipse_how(
+    kind: EclipseKind,
+    julian_day: f64,
+    latitude: f64,
+    longitude: f64,
+) -> Result<(i32, [f64; 20]), String> {
+    let mut geopos: [f64; 3] = [longitude, latitude, 0.0];
+    let mut attr: [f64; 20] = [0.0; 20];
+    let mut serr: [i8; 256] = [0; 256];
+    let flags = unsafe {
+        match kind {
+            EclipseKind::Solar => libswisseph_sys::swe_sol_eclipse_how(
+                julian_day,
+                SEFLG_SWIEPH,
+                geopos.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                serr.as_mut_ptr(),
+            ),
+            EclipseKind::Lunar => libswisseph_sys::swe_lun_eclipse_how(
+                julian_day,
+                SEFLG_SWIEPH,
+                geopos.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                serr.as_mut_ptr(),
+            ),
+        }
+    };
+    if flags < 0 {
+        return Err(format!(
+            "Local eclipse calculation failed: {}",
+            swe_error_message(&serr)
+        ));
+    }
+    Ok((flags, attr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::{date_to_julian_day, julian_day_to_timestamp};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_eclipses_of_2024() {
+        let start = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let end = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        let eclipses = find_eclipses(start, end, None).unwrap();
+
+        // Penumbral lunar (Mar 25), total solar (Apr 8), partial lunar (Sep 18), annular solar (Oct 2)
+        let types: Vec<_> = eclipses.iter().map(|e| (e.kind, e.eclipse_type)).collect();
+        assert_eq!(
+            types,
+            vec![
+                (EclipseKind::Lunar, EclipseType::Penumbral),
+                (EclipseKind::Solar, EclipseType::Total),
+                (EclipseKind::Lunar, EclipseType::Partial),
+                (EclipseKind::Solar, EclipseType::Annular),
+            ]
+        );
+
+        // Greatest eclipse of April 8, 2024 at 18:17 UT, saros 139
+        let total = &eclipses[1];
+        assert!(julian_day_to_timestamp(total.maximum).starts_with("2024-04-08T18:1"));
+        assert_eq!(total.saros_series, Some(139));
+        assert!(total.central && total.magnitude > 1.0);
+    }
+
+    #[test]
+    fn test_previous_eclipse() {
+        // The hybrid eclipse of April 20, 2023 was the last solar eclipse before May 2023
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2023, 5, 1).unwrap());
+        let eclipse = find_eclipse(jd, Some(EclipseKind::Solar), true).unwrap();
+        assert_eq!(eclipse.eclipse_type, EclipseType::Hybrid);
+        assert!(julian_day_to_timestamp(eclipse.maximum).starts_with("2023-04-20"));
+        assert_eq!(eclipse.saros_series, Some(129));
+    }
+
+    #[test]
+    fn test_prenatal_eclipses() {
+        // Born April 1, 2024: after the penumbral lunar eclipse of March 25 but before
+        // the total solar eclipse of April 8, so the prenatal solar eclipse is October 14, 2023
+        let birth = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        let [solar, lunar] = prenatal_eclipses(birth).unwrap();
+        assert_eq!(solar.eclipse_type, EclipseType::Annular);
+        assert!(julian_day_to_timestamp(solar.maximum).starts_with("2023-10-14"));
+        assert_eq!(lunar.eclipse_type, EclipseType::Penumbral);
+        assert!(julian_day_to_timestamp(lunar.maximum).starts_with("2024-03-25"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Fixed star positions
+//!
+//! Stars come from a catalog bundled with the crate, so they work without any ephemeris
+//! files. Swiss Ephemeris applies proper motion and precession for the requested date.
+//! A full `sefstars.txt` in the configured ephemeris directory takes precedence.
+
+use std::ffi::CStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::models::ZodiacMode;
+
+use super::calculator::{init_ephemeris, swe_error_message, with_zodiac};
+
+const SEFLG_SWIEPH: i32 = 2;
+
+/// Bundled star catalog in the Swiss Ephemeris sefstars.txt format
+const STAR_CATALOG: &str = include_str!("../../data/sefstars.txt");
+
+/// Default orb for conjunctions and oppositions to fixed stars (degrees of longitude)
+pub const DEFAULT_FIXED_STAR_ORB: f64 = 1.0;
+
+/// Position of a fixed star on a given date
+#[derive(Debug, Clone)]
+pub struct FixedStarPosition {
+    /// Traditional name (e.g., "Regulus")
+    pub name: String,
+    /// Bayer/Flamsteed designation as written in the catalog (e.g., "alLeo")
+    pub nomenclature: String,
+    /// Ecliptic longitude (0-360 degrees)
+    pub longitude: f64,
+    /// Ecliptic latitude
+    pub latitude: f64,
+    /// Visual magnitude
+    pub magnitude: f64,
+}
+
+/// Names of the stars in the bundled catalog, in catalog order
+pub fn bundled_star_names() -> Vec<&'static str> {
+    STAR_CATALOG
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .filter_map(|line| line.split(',').next())
+        .collect()
+}
+
+/// Where the bundled catalog was written, or why it couldn't be
+static BUNDLED_CATALOG: OnceLock<Result<PathBuf, String>> = OnceLock::new();
+
+/// Write the bundled catalog where Swiss Ephemeris can find it and return its directory
+/// Swiss Ephemeris only reads stars from a file on its search path. The file goes in the
+/// per-user data directory, which other users can't write to, rather than a shared temporary one.
+pub(crate) fn bundled_catalog_dir() -> Result<PathBuf, String> {
+    BUNDLED_CATALOG
+        .get_or_init(|| {
+            let root = catalog_root().ok_or("no per-user data directory was found")?;
+            let dir = root.join(format!("stars-{}", env!("CARGO_PKG_VERSION")));
+            install_catalog(&dir)?;
+            Ok(dir)
+        })
+        .clone()
+}
+
+/// Directory the catalog is installed under
+#[cfg(not(test))]
+fn catalog_root() -> Option<PathBuf> {
+    crate::storage::data_dir()
+}
+
+/// Tests install the catalog in a temporary directory rather than the user's data directory
+#[cfg(test)]
+fn catalog_root() -> Option<PathBuf> {
+    Some(std::env::temp_dir().join(format!("stellium-test-{}-stars", std::process::id())))
+}
+
+/// Write the bundled catalog to `sefstars.txt` in a directory, unless it is already there
+fn install_catalog(dir: &Path) -> Result<(), String> {
+    let file = dir.join("sefstars.txt");
+    if fs::read_to_string(&file).ok().as_deref() == Some(STAR_CATALOG) {
+        return Ok(());
+    }
+    let write = || -> std::io::Result<()> {
+        fs::create_dir_all(dir)?;
+        // Write then rename, so a concurrent server never reads a partial file
+        let partial = dir.join(format!("sefstars.txt.{}", std::process::id()));
+        fs::write(&partial, STAR_CATALOG)?;
+        fs::rename(&partial, &file)
+    };
+    write().map_err(|e| {
+        format!(
+            "Failed to write the star catalog to {}: {}",
+            dir.display(),
+            e
+        )
+    })
+}
+
+/// Calculate a fixed star's position at a given Julian Day in the given zodiac
+pub fn calc_fixed_star(
+    name: &str,
+    julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<FixedStarPosition, String> {
+    init_ephemeris();
+
+    // Swiss Ephemeris overwrites the name with "name,nomenclature"
+    let mut star = star_buffer(name.trim());
+    let mut xx: [f64; 6] = [0.0; 6];
+    let mut serr: [i8; 256] = [0; 256];
+
+    let ret = with_zodiac(zodiac, |zodiac_flags| unsafe {
+        libswisseph_sys::swe_fixstar2_ut(
+            star.as_mut_ptr(),
+            julian_day,
+            SEFLG_SWIEPH | zodiac_flags,
+            xx.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    });
+
+    if ret < 0 {
+        let message = swe_error_message(&serr);
+        if message.contains("could not find star") {
+            if let Err(e) = bundled_catalog_dir() {
+                return Err(format!(
+                    "Fixed star '{}' could not be looked up: the bundled star catalog is not installed ({})",
+                    name, e
+                ));
+            }
+            return Err(format!("Fixed star '{}' is not in the star catalog", name));
+        }
+        return Err(format!("Fixed star '{}': {}", name, message));
+    }
+
+    let full_name = unsafe { CStr::from_ptr(star.as_ptr()) }
+        .to_string_lossy()
+        .to_string();
+    let (star_name, nomenclature) = full_name.split_once(',').unwrap_or((&full_name, ""));
+
+    let mut magnitude = 0.0;
+    let mut star = star_buffer(star_name);
+    let ret = unsafe {
+        libswisseph_sys::swe_fixstar2_mag(star.as_mut_ptr(), &mut magnitude, serr.as_mut_ptr())
+    };
+    if ret < 0 {
+        return Err(format!(
+            "Fixed star '{}': {}",
+            name,
+            swe_error_message(&serr)
+        ));
+    }
+
+    Ok(FixedStarPosition {
+        name: star_name.to_string(),
+        nomenclature: nomenclature.to_string(),
+        longitude: xx[0],
+        latitude: xx[1],
+        magnitude,
+    })
+}
+
+/// A NUL-terminated name buffer of the size Swiss Ephemeris expects (SE_MAX_STNAME)
+fn star_buffer(name: &str) -> [i8; 256] {
+    let mut buffer: [i8; 256] = [0; 256];
+    let bytes = name.bytes().take_while(|b| *b != 0).take(255);
+    for (slot, byte) in buffer.iter_mut().zip(bytes) {
+        *slot = byte as i8;
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::date_to_julian_day;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_bundled_royal_stars() {
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+
+        // Tropical longitudes for J2000: Regulus 29°50' Leo, Spica 23°50' Libra
+        let regulus = calc_fixed_star("Regulus", jd, ZodiacMode::Tropical).unwrap();
+        assert!((regulus.longitude - 149.83).abs() < 0.05);
+        assert_eq!(regulus.nomenclature, "alLeo");
+
+        let spica = calc_fixed_star("Spica", jd, ZodiacMode::Tropical).unwrap();
+        assert!((spica.longitude - 203.84).abs() < 0.05);
+        assert!(spica.magnitude < 1.5);
+
+        assert!(calc_fixed_star("Nonexistent Star", jd, ZodiacMode::Tropical).is_err());
+    }
+
+    #[test]
+    fn test_bundled_catalog_dir() {
+        let dir = bundled_catalog_dir().unwrap();
+        assert!(dir.starts_with(catalog_root().unwrap()));
+        assert_eq!(
+            fs::read_to_string(dir.join("sefstars.txt")).unwrap(),
+            STAR_CATALOG
+        );
+    }
+
+    #[test]
+    fn test_install_catalog() {
+        let root = std::env::temp_dir().join(format!(
+            "stellium-test-{}-install-catalog",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+
+        // Installing twice leaves a single complete copy
+        let dir = root.join("stars");
+        install_catalog(&dir).unwrap();
+        install_catalog(&dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("sefstars.txt")).unwrap(),
+            STAR_CATALOG
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        // A directory that can't be created is reported
+        fs::write(root.join("file"), "").unwrap();
+        let err = install_catalog(&root.join("file").join("stars")).unwrap_err();
+        assert!(
+            err.starts_with("Failed to write the star catalog"),
+            "{}",
+            err
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! House system calculations using Swiss Ephemeris
 
-use crate::models::ZodiacPosition;
+use crate::models::{HouseSystem, Planet, ZodiacMode, ZodiacPosition};
+
+use super::calculator::{calc_planet_position, find_speed_change, swe_error_message, with_zodiac};
 
 // House system codes for Swiss Ephemeris
 #[allow(dead_code)]
@@ -43,32 +45,50 @@ pub fn calc_houses(
     latitude: f64,
     longitude: f64,
     house_system: i8,
+    zodiac: ZodiacMode,
 ) -> Result<HousePositions, String> {
     // Swiss Ephemeris uses a 13-element array for cusps (index 1-12)
     // and a 10-element array for special points
     let mut cusps: [f64; 13] = [0.0; 13];
     let mut ascmc: [f64; 10] = [0.0; 10];
+    let mut serr: [i8; 256] = [0; 256];
 
-    let ret = unsafe {
-        libswisseph_sys::swe_houses(
+    let ret = with_zodiac(zodiac, |zodiac_flags| unsafe {
+        libswisseph_sys::swe_houses_ex2(
             julian_day,
+            zodiac_flags,
             latitude,
             longitude,
             house_system as i32,
             cusps.as_mut_ptr(),
             ascmc.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            serr.as_mut_ptr(),
         )
-    };
+    });
 
+    // On error Swiss Ephemeris silently fills in Porphyry cusps, so never use them
     if ret < 0 {
-        return Err("Failed to calculate houses".to_string());
+        let error_msg = swe_error_message(&serr);
+        if error_msg.is_empty() {
+            return Err(format!(
+                "{} houses could not be calculated at latitude {:.2}°",
+                house_system_name(house_system),
+                latitude
+            ));
+        }
+        return Err(format!(
+            "{} houses could not be calculated at latitude {:.2}° (Swiss Ephemeris: {})",
+            house_system_name(house_system),
+            latitude,
+            error_msg
+        ));
     }
 
     // Convert from 1-indexed to 0-indexed for our cusps array
     let mut house_cusps: [f64; 12] = [0.0; 12];
-    for i in 0..12 {
-        house_cusps[i] = cusps[i + 1];
-    }
+    house_cusps.copy_from_slice(&cusps[1..13]);
 
     Ok(HousePositions {
         ascendant: ascmc[0],
@@ -79,13 +99,37 @@ pub fn calc_houses(
     })
 }
 
+/// Calculate houses, switching to a fallback system when the requested one fails
+/// (Placidus and Koch are undefined inside the polar circles).
+/// Returns the houses and, when the fallback was used, the reason the requested system failed.
+pub fn calc_houses_with_fallback(
+    julian_day: f64,
+    latitude: f64,
+    longitude: f64,
+    house_system: i8,
+    fallback: Option<i8>,
+    zodiac: ZodiacMode,
+) -> Result<(HousePositions, Option<String>), String> {
+    match calc_houses(julian_day, latitude, longitude, house_system, zodiac) {
+        Ok(houses) => Ok((houses, None)),
+        Err(reason) => match fallback {
+            Some(fallback) if fallback != house_system => {
+                let houses = calc_houses(julian_day, latitude, longitude, fallback, zodiac)?;
+                Ok((houses, Some(reason)))
+            }
+            _ => Err(reason),
+        },
+    }
+}
+
 /// Calculate Ascendant and Midheaven only (faster than full house calculation)
 pub fn calc_asc_mc(
     julian_day: f64,
     latitude: f64,
     longitude: f64,
+    zodiac: ZodiacMode,
 ) -> Result<(ZodiacPosition, ZodiacPosition), String> {
-    let houses = calc_houses(julian_day, latitude, longitude, HOUSE_PLACIDUS)?;
+    let houses = calc_houses(julian_day, latitude, longitude, HOUSE_PLACIDUS, zodiac)?;
     Ok((houses.ascendant_position(), houses.midheaven_position()))
 }
 
@@ -117,18 +161,245 @@ pub fn planet_in_house(planet_longitude: f64, house_cusps: &[f64; 12]) -> u8 {
     1
 }
 
+/// Find every time a planet moves into another natal house between two Julian Days
+/// Returns (julian_day, new_house, is_retrograde); retrograde ingresses move back into the previous house.
+pub fn find_house_ingresses(
+    planet: Planet,
+    house_cusps: &[f64; 12],
+    start_julian_day: f64,
+    end_julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<Vec<(f64, u8, bool)>, String> {
+    // Small enough steps that a planet can't cross a typical house and back between samples
+    let step = match planet {
+        Planet::Moon => 0.1,
+        Planet::Sun | Planet::Mercury | Planet::Venus => 0.5,
+        Planet::TrueLilith => 0.5,
+        Planet::Mars | Planet::NorthNode | Planet::SouthNode => 1.0,
+        _ => 2.0,
+    };
+    let house_at = |jd: f64| -> Result<u8, String> {
+        Ok(planet_in_house(
+            calc_planet_position(planet, jd, zodiac)?.longitude,
+            house_cusps,
+        ))
+    };
+
+    let speed_at = |jd: f64| -> Result<f64, String> {
+        Ok(calc_planet_position(planet, jd, zodiac)?.speed_longitude)
+    };
+
+    let mut ingresses = Vec::new();
+    let mut jd = start_julian_day;
+    let mut house = house_at(jd)?;
+    let mut speed = speed_at(jd)?;
+
+    while jd < end_julian_day {
+        let next_jd = (jd + step).min(end_julian_day);
+        let next_speed = speed_at(next_jd)?;
+
+        // Around a station a planet can cross a cusp and come back within one step,
+        // so split the step where its motion changes direction
+        let stretches = if (speed < 0.0) != (next_speed < 0.0) {
+            let station = find_speed_change(planet, jd, next_jd, zodiac)?;
+            vec![(jd, station), (station, next_jd)]
+        } else {
+            vec![(jd, next_jd)]
+        };
+
+        for (mut low, mut high) in stretches {
+            let next_house = house_at(high)?;
+            if next_house == house {
+                continue;
+            }
+
+            // Refine the cusp crossing with binary search (~1.4 minutes precision)
+            while high - low > 0.001 {
+                let mid = (low + high) / 2.0;
+                if house_at(mid)? == house {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            let position = calc_planet_position(planet, high, zodiac)?;
+            ingresses.push((high, next_house, position.is_retrograde));
+            house = next_house;
+        }
+
+        jd = next_jd;
+        speed = next_speed;
+    }
+
+    Ok(ingresses)
+}
+
 /// Get house system name from code
 pub fn house_system_name(code: i8) -> &'static str {
-    match code as u8 as char {
-        'P' => "Placidus",
-        'K' => "Koch",
-        'E' => "Equal",
-        'W' => "Whole Sign",
-        'R' => "Regiomontanus",
-        'C' => "Campanus",
-        'B' => "Alcabitius",
-        'M' => "Morinus",
-        'O' => "Porphyry",
-        _ => "Unknown",
+    HouseSystem::from_code(code)
+        .map(|system| system.name())
+        .unwrap_or("Unknown")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::{date_to_julian_day, julian_day_to_timestamp};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_polar_latitude_fallback() {
+        // 2000-01-01 12:00 UT in Tromsø, well inside the Arctic Circle
+        let jd = 2451545.0;
+        let latitude = 69.65;
+        let longitude = 18.96;
+
+        let err = calc_houses(
+            jd,
+            latitude,
+            longitude,
+            HOUSE_PLACIDUS,
+            ZodiacMode::Tropical,
+        )
+        .unwrap_err();
+        assert!(err.contains("Placidus"));
+
+        let (houses, reason) = calc_houses_with_fallback(
+            jd,
+            latitude,
+            longitude,
+            HOUSE_PLACIDUS,
+            Some(HOUSE_WHOLE_SIGN),
+            ZodiacMode::Tropical,
+        )
+        .unwrap();
+        assert!(reason.is_some());
+        assert_eq!(houses.cusps[0] % 30.0, 0.0);
+
+        let rejected = calc_houses_with_fallback(
+            jd,
+            latitude,
+            longitude,
+            HOUSE_PLACIDUS,
+            None,
+            ZodiacMode::Tropical,
+        );
+        assert!(rejected.is_err());
+    }
+
+    /// Twelve equal houses starting at the given longitude
+    fn equal_houses(first_cusp: f64) -> [f64; 12] {
+        std::array::from_fn(|i| (first_cusp + 30.0 * i as f64).rem_euclid(360.0))
+    }
+
+    fn julian_day(year: i32, month: u32, day: u32) -> f64 {
+        date_to_julian_day(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    #[test]
+    fn test_house_ingresses() {
+        // With houses starting at 0° Aries, the Sun changes house when it changes sign
+        let cusps = equal_houses(0.0);
+        let ingresses = find_house_ingresses(
+            Planet::Sun,
+            &cusps,
+            julian_day(2024, 1, 1),
+            julian_day(2024, 4, 1),
+            ZodiacMode::Tropical,
+        )
+        .unwrap();
+
+        // The Sun entered Aquarius, Pisces and Aries at these times (UT)
+        let expected = [
+            (julian_day(2024, 1, 20) + (14.0 + 7.0 / 60.0) / 24.0, 11),
+            (julian_day(2024, 2, 19) + (4.0 + 13.0 / 60.0) / 24.0, 12),
+            (julian_day(2024, 3, 20) + (3.0 + 6.0 / 60.0) / 24.0, 1),
+        ];
+        assert_eq!(ingresses.len(), expected.len());
+        for ((jd, house, retrograde), (expected_jd, expected_house)) in
+            ingresses.iter().zip(expected)
+        {
+            assert_eq!((*house, *retrograde), (expected_house, false));
+            assert!(
+                (jd - expected_jd).abs() < 2.0 / 1440.0,
+                "{}",
+                julian_day_to_timestamp(*jd)
+            );
+        }
+    }
+
+    #[test]
+    fn test_retrograde_house_re_entry() {
+        // Pluto crossed 0° Aquarius five times in 2023-2024
+        let cusps = equal_houses(300.0);
+        let ingresses = find_house_ingresses(
+            Planet::Pluto,
+            &cusps,
+            julian_day(2023, 1, 1),
+            julian_day(2025, 1, 1),
+            ZodiacMode::Tropical,
+        )
+        .unwrap();
+
+        let summary: Vec<(String, u8, bool)> = ingresses
+            .iter()
+            .map(|(jd, house, retrograde)| {
+                (
+                    julian_day_to_timestamp(*jd)[..10].to_string(),
+                    *house,
+                    *retrograde,
+                )
+            })
+            .collect();
+        let expected = [
+            ("2023-03-23", 1, false),
+            ("2023-06-11", 12, true),
+            ("2024-01-21", 1, false),
+            ("2024-09-01", 12, true),
+            ("2024-11-19", 1, false),
+        ];
+        assert_eq!(summary.len(), expected.len());
+        for ((date, house, retrograde), (expected_date, expected_house, expected_retrograde)) in
+            summary.iter().zip(expected)
+        {
+            assert_eq!(
+                (date.as_str(), *house, *retrograde),
+                (expected_date, expected_house, expected_retrograde)
+            );
+        }
+    }
+
+    #[test]
+    fn test_house_ingress_around_a_station() {
+        // A cusp just short of where Mercury stationed retrograde on 1970-01-04 is crossed
+        // and recrossed within a couple of hours
+        let station = find_speed_change(
+            Planet::Mercury,
+            julian_day(1970, 1, 3),
+            julian_day(1970, 1, 5),
+            ZodiacMode::Tropical,
+        )
+        .unwrap();
+        let station_longitude =
+            calc_planet_position(Planet::Mercury, station, ZodiacMode::Tropical)
+                .unwrap()
+                .longitude;
+        let cusps = equal_houses(station_longitude - 0.0005);
+
+        let ingresses = find_house_ingresses(
+            Planet::Mercury,
+            &cusps,
+            julian_day(1970, 1, 1),
+            julian_day(1970, 1, 8),
+            ZodiacMode::Tropical,
+        )
+        .unwrap();
+        assert_eq!(ingresses.len(), 2);
+        let ((enters, house, retrograde), (leaves, previous_house, back_retrograde)) =
+            (ingresses[0], ingresses[1]);
+        assert_eq!((house, retrograde), (1, false));
+        assert_eq!((previous_house, back_retrograde), (12, true));
+        assert!(enters < station && station < leaves && leaves - enters < 0.5);
     }
 }
@@ -1,5 +1,17 @@
 pub mod calculator;
+pub mod eclipse_path;
+pub mod eclipses;
+pub mod fixed_stars;
 pub mod houses;
+pub mod retrograde;
+pub mod transit_aspects;
+pub mod void_of_course;
 
 pub use calculator::*;
+pub use eclipse_path::*;
+pub use eclipses::*;
+pub use fixed_stars::*;
 pub use houses::*;
+pub use retrograde::*;
+pub use transit_aspects::*;
+pub use void_of_course::*;
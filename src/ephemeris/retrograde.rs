@@ -0,0 +1,224 @@
+//! Retrograde cycles and shadow periods
+//!
+//! The pre-retrograde shadow starts when a planet first reaches the degree it will
+//! later station direct on; the post-retrograde shadow ends when it clears the degree
+//! it stationed retrograde on.
+
+use crate::models::{Planet, ZodiacMode};
+
+use super::calculator::{
+    angle_past, calc_planet_position, find_next_station, find_previous_station,
+};
+
+/// Longest retrograde period (Pluto, about 160 days) with margin
+const MAX_RETROGRADE_DAYS: i32 = 200;
+
+/// Longest shadow period (slow outer planets) with margin
+const MAX_SHADOW_DAYS: i32 = 400;
+
+/// One retrograde cycle: both stations and the shadow periods around them
+#[derive(Debug, Clone)]
+pub struct RetrogradeCycle {
+    pub planet: Planet,
+    /// When the planet stations retrograde
+    pub retrograde_station: f64,
+    /// Tropical longitude of the retrograde station
+    pub retrograde_longitude: f64,
+    /// When the planet stations direct
+    pub direct_station: Option<f64>,
+    /// Tropical longitude of the direct station
+    pub direct_longitude: Option<f64>,
+    /// When the planet first reaches the direct-station degree
+    pub pre_shadow_start: Option<f64>,
+    /// When the planet passes the retrograde-station degree again
+    pub post_shadow_end: Option<f64>,
+}
+
+/// Find when a planet's longitude crosses a target degree, searching forward or backward
+fn find_longitude_crossing(
+    planet: Planet,
+    start_julian_day: f64,
+    target: f64,
+    max_days: i32,
+    forward: bool,
+) -> Result<Option<f64>, String> {
+    let step = if forward { 1.0 } else { -1.0 };
+    let distance = |jd: f64| -> Result<f64, String> {
+        let pos = calc_planet_position(planet, jd, ZodiacMode::Tropical)?;
+        Ok(angle_past(pos.longitude, target))
+    };
+
+    let mut prev_jd = start_julian_day;
+    let mut prev = distance(prev_jd)?;
+
+    for _ in 0..max_days {
+        let jd = prev_jd + step;
+        let current = distance(jd)?;
+
+        // A sign change near zero (not the jump at ±180°) is a crossing
+        if (prev < 0.0) != (current < 0.0) && (prev - current).abs() < 90.0 {
+            let mut low = prev_jd;
+            let mut high = jd;
+
+            while (high - low).abs() > 0.0005 {
+                let mid = (low + high) / 2.0;
+                if (distance(mid)? < 0.0) == (prev < 0.0) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            return Ok(Some(high));
+        }
+
+        prev_jd = jd;
+        prev = current;
+    }
+
+    Ok(None)
+}
+
+/// Build the retrograde cycle that starts with the given retrograde station
+pub fn find_retrograde_cycle(
+    planet: Planet,
+    retrograde_station: f64,
+) -> Result<RetrogradeCycle, String> {
+    let retrograde_longitude =
+        calc_planet_position(planet, retrograde_station, ZodiacMode::Tropical)?.longitude;
+
+    // Start just past the station so the search sees retrograde motion
+    let direct_station = find_next_station(planet, retrograde_station + 0.01, MAX_RETROGRADE_DAYS)?
+        .map(|(jd, _)| jd);
+    let direct_longitude = match direct_station {
+        Some(jd) => Some(calc_planet_position(planet, jd, ZodiacMode::Tropical)?.longitude),
+        None => None,
+    };
+
+    let pre_shadow_start = match direct_longitude {
+        Some(lon) => {
+            find_longitude_crossing(planet, retrograde_station, lon, MAX_SHADOW_DAYS, false)?
+        }
+        None => None,
+    };
+    let post_shadow_end = match direct_station {
+        Some(jd) => {
+            find_longitude_crossing(planet, jd, retrograde_longitude, MAX_SHADOW_DAYS, true)?
+        }
+        None => None,
+    };
+
+    Ok(RetrogradeCycle {
+        planet,
+        retrograde_station,
+        retrograde_longitude,
+        direct_station,
+        direct_longitude,
+        pre_shadow_start,
+        post_shadow_end,
+    })
+}
+
+/// The retrograde cycle a planet is in at the given moment, if it is retrograde
+pub fn find_current_retrograde_cycle(
+    planet: Planet,
+    julian_day: f64,
+) -> Result<Option<RetrogradeCycle>, String> {
+    let pos = calc_planet_position(planet, julian_day, ZodiacMode::Tropical)?;
+    if !pos.is_retrograde {
+        return Ok(None);
+    }
+
+    match find_previous_station(planet, julian_day, MAX_RETROGRADE_DAYS)? {
+        Some((station, true)) => find_retrograde_cycle(planet, station).map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::{date_to_julian_day, datetime_to_julian_day};
+    use chrono::NaiveDate;
+
+    fn julian_day(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> f64 {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        datetime_to_julian_day(date.and_hms_opt(hour, minute, 0).unwrap())
+    }
+
+    fn assert_near(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_mercury_retrograde_2024() {
+        // Mercury stationed retrograde on 2024-04-01 at 27°13' Aries and direct on 2024-04-25 at 15°58' Aries
+        let jd = date_to_julian_day(NaiveDate::from_ymd_opt(2024, 4, 10).unwrap());
+        let cycle = find_current_retrograde_cycle(Planet::Mercury, jd)
+            .unwrap()
+            .unwrap();
+
+        let minutes = 5.0 / 1440.0;
+        assert_near(
+            cycle.retrograde_station,
+            julian_day(2024, 4, 1, 22, 14),
+            minutes,
+        );
+        assert_near(cycle.retrograde_longitude, 27.0 + 13.0 / 60.0, 0.02);
+        assert_near(
+            cycle.direct_station.unwrap(),
+            julian_day(2024, 4, 25, 12, 54),
+            minutes,
+        );
+        assert_near(cycle.direct_longitude.unwrap(), 15.0 + 58.0 / 60.0, 0.02);
+
+        // The shadow runs from Mercury first reaching 15°58' Aries to it passing 27°13' Aries again
+        let pre_shadow_start = cycle.pre_shadow_start.unwrap();
+        let post_shadow_end = cycle.post_shadow_end.unwrap();
+        assert_near(pre_shadow_start, julian_day(2024, 3, 19, 3, 39), minutes);
+        assert_near(post_shadow_end, julian_day(2024, 5, 13, 9, 2), minutes);
+        let longitude = |jd: f64| {
+            calc_planet_position(Planet::Mercury, jd, ZodiacMode::Tropical)
+                .unwrap()
+                .longitude
+        };
+        assert_near(
+            longitude(pre_shadow_start),
+            cycle.direct_longitude.unwrap(),
+            0.001,
+        );
+        assert_near(
+            longitude(post_shadow_end),
+            cycle.retrograde_longitude,
+            0.001,
+        );
+
+        // The cycle found forward from its retrograde station is the same one
+        let forward = find_retrograde_cycle(Planet::Mercury, cycle.retrograde_station).unwrap();
+        assert_eq!(forward.direct_station, cycle.direct_station);
+        assert_eq!(forward.pre_shadow_start, cycle.pre_shadow_start);
+    }
+
+    #[test]
+    fn test_no_current_cycle_while_direct() {
+        // Mercury was direct in the pre-shadow, and the station search looks back from a direct planet
+        let jd = julian_day(2024, 3, 25, 0, 0);
+        assert!(find_current_retrograde_cycle(Planet::Mercury, jd)
+            .unwrap()
+            .is_none());
+
+        let (station, is_retrograde) =
+            find_previous_station(Planet::Mercury, jd, MAX_RETROGRADE_DAYS)
+                .unwrap()
+                .unwrap();
+        assert!(!is_retrograde);
+        // The previous station was the direct station of 2024-01-02 03:07 UTC
+        assert_near(station, julian_day(2024, 1, 2, 3, 7), 5.0 / 1440.0);
+    }
+}
@@ -0,0 +1,448 @@
+//! Transit-to-natal aspect windows
+//!
+//! A window runs from the moment a transiting planet comes within orb of an aspect
+//! to a natal point until it leaves orb. Retrograde planets can perfect the same
+//! aspect up to three times inside one window. Parallels and contra-parallels of
+//! declination are found the same way.
+
+use std::fmt;
+
+use crate::models::{AspectType, ChartPoint, DeclinationAspect, OrbPolicy, Planet, ZodiacMode};
+
+use super::calculator::{angle_past, calc_planet_position};
+
+/// The contact a window is for: an aspect in longitude, or a parallel of declination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitAspect {
+    Longitude(AspectType),
+    Declination(DeclinationAspect),
+}
+
+impl fmt::Display for TransitAspect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitAspect::Longitude(aspect) => write!(f, "{}", aspect),
+            TransitAspect::Declination(aspect) => {
+                write!(f, "{}", aspect.to_string().to_lowercase())
+            }
+        }
+    }
+}
+
+/// An aspect window between a transiting planet and a natal point, clipped to the search range
+#[derive(Debug, Clone)]
+pub struct TransitAspectWindow {
+    pub transit_planet: Planet,
+    pub natal_point: ChartPoint,
+    pub aspect: TransitAspect,
+    /// When the transit comes within orb (None if already in orb at the start of the range)
+    pub enters_orb: Option<f64>,
+    /// When the transit leaves orb (None if still in orb at the end of the range)
+    pub leaves_orb: Option<f64>,
+    /// Each exact perfection inside the range
+    pub exact: Vec<f64>,
+    /// Smallest orb reached inside the range
+    pub closest_orb: f64,
+}
+
+/// Sampling step in days. Narrow windows can still fit between two samples and are found from their perfection.
+fn sampling_step(planet: Planet) -> f64 {
+    match planet {
+        Planet::Moon => 0.1,
+        Planet::Sun | Planet::Mercury | Planet::Venus => 0.5,
+        Planet::TrueLilith => 0.5,
+        Planet::Mars | Planet::NorthNode | Planet::SouthNode => 1.0,
+        _ => 2.0,
+    }
+}
+
+/// Separations (transit minus natal) at which an aspect is exact
+fn aspect_targets(aspect: AspectType) -> Vec<f64> {
+    let angle = aspect.angle();
+    if angle == 0.0 || angle == 180.0 {
+        vec![angle]
+    } else {
+        vec![angle, 360.0 - angle]
+    }
+}
+
+/// Refine the moment a condition changes between two Julian Days (~9 seconds precision)
+fn bisect(
+    mut low: f64,
+    mut high: f64,
+    condition: impl Fn(f64) -> Result<bool, String>,
+) -> Result<f64, String> {
+    let low_value = condition(low)?;
+    while high - low > 0.0001 {
+        let mid = (low + high) / 2.0;
+        if condition(mid)? == low_value {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(high)
+}
+
+/// Sample a value of the transiting planet across the range, ending exactly on its last day
+fn sample(
+    transit_planet: Planet,
+    start_julian_day: f64,
+    end_julian_day: f64,
+    value: impl Fn(f64) -> Result<f64, String>,
+) -> Result<Vec<(f64, f64)>, String> {
+    let step = sampling_step(transit_planet);
+    let mut samples = Vec::new();
+    let mut jd = start_julian_day;
+    loop {
+        samples.push((jd, value(jd)?));
+        if jd >= end_julian_day {
+            break;
+        }
+        jd = (jd + step).min(end_julian_day);
+    }
+    Ok(samples)
+}
+
+/// Scan a signed distance from exact for the stretches within orb
+/// `distance` gives the distance at any moment and `samples` holds it at the sampled ones.
+fn orb_windows(
+    samples: &[(f64, f64)],
+    orb: f64,
+    distance: impl Fn(f64) -> Result<f64, String>,
+    new_window: impl Fn(Option<f64>) -> TransitAspectWindow,
+) -> Result<Vec<TransitAspectWindow>, String> {
+    let in_orb = |jd: f64| -> Result<bool, String> { Ok(distance(jd)?.abs() <= orb) };
+    let mut windows = Vec::new();
+    let mut current: Option<TransitAspectWindow> = None;
+    let mut prev: Option<(f64, f64)> = None;
+
+    for &(jd, d) in samples {
+        match prev {
+            None => {
+                if d.abs() <= orb {
+                    current = Some(new_window(None));
+                }
+            }
+            Some((prev_jd, prev_d)) => {
+                if prev_d.abs() > orb && d.abs() <= orb {
+                    current = Some(new_window(Some(bisect(prev_jd, jd, in_orb)?)));
+                }
+
+                // A sign change near zero (not the jump at ±180°) is a perfection
+                if (prev_d < 0.0) != (d < 0.0) && (prev_d - d).abs() < 90.0 {
+                    let exact = bisect(prev_jd, jd, |t| Ok(distance(t)? < 0.0))?;
+                    match current.as_mut() {
+                        Some(window) => window.exact.push(exact),
+                        // The whole window fits between two samples: find its edges on either side of the perfection
+                        None => {
+                            let mut window = new_window(Some(bisect(prev_jd, exact, in_orb)?));
+                            window.exact.push(exact);
+                            window.leaves_orb = Some(bisect(exact, jd, in_orb)?);
+                            windows.push(window);
+                        }
+                    }
+                }
+
+                if prev_d.abs() <= orb && d.abs() > orb {
+                    if let Some(mut window) = current.take() {
+                        window.leaves_orb = Some(bisect(prev_jd, jd, in_orb)?);
+                        windows.push(window);
+                    }
+                }
+            }
+        }
+
+        if let Some(window) = current.as_mut() {
+            window.closest_orb = window.closest_orb.min(d.abs());
+        }
+        prev = Some((jd, d));
+    }
+
+    windows.extend(current);
+    for window in &mut windows {
+        if !window.exact.is_empty() {
+            window.closest_orb = 0.0;
+        }
+    }
+    Ok(windows)
+}
+
+/// Find every aspect window a transiting planet makes to the given natal points (planets, angles or cusps)
+/// Orbs come from the policy, so each window's width depends on the planets involved.
+pub fn find_transit_aspect_windows(
+    transit_planet: Planet,
+    natal_points: &[(ChartPoint, f64)],
+    aspects: &[AspectType],
+    orbs: &OrbPolicy,
+    start_julian_day: f64,
+    end_julian_day: f64,
+    zodiac: ZodiacMode,
+) -> Result<Vec<TransitAspectWindow>, String> {
+    let longitude = |jd: f64| -> Result<f64, String> {
+        Ok(calc_planet_position(transit_planet, jd, zodiac)?.longitude)
+    };
+
+    // Sample the transiting planet once and scan every natal point against it
+    let samples = sample(transit_planet, start_julian_day, end_julian_day, longitude)?;
+    let mut windows = Vec::new();
+
+    for &(natal_point, natal_longitude) in natal_points {
+        for &aspect in aspects {
+            let orb = orbs.point_orb(aspect, ChartPoint::Planet(transit_planet), natal_point);
+
+            for target in aspect_targets(aspect) {
+                let distances: Vec<(f64, f64)> = samples
+                    .iter()
+                    .map(|&(jd, lon)| (jd, angle_past(lon - natal_longitude, target)))
+                    .collect();
+                windows.extend(orb_windows(
+                    &distances,
+                    orb,
+                    |jd| Ok(angle_past(longitude(jd)? - natal_longitude, target)),
+                    |enters_orb| {
+                        new_window(
+                            transit_planet,
+                            natal_point,
+                            TransitAspect::Longitude(aspect),
+                            enters_orb,
+                        )
+                    },
+                )?);
+            }
+        }
+    }
+
+    Ok(windows)
+}
+
+/// Find every parallel and contra-parallel window a transiting planet makes to the given natal
+/// declinations, when the orb policy looks for them
+pub fn find_transit_parallel_windows(
+    transit_planet: Planet,
+    natal_declinations: &[(ChartPoint, f64)],
+    orbs: &OrbPolicy,
+    start_julian_day: f64,
+    end_julian_day: f64,
+) -> Result<Vec<TransitAspectWindow>, String> {
+    let Some(orb) = orbs.declination_orb() else {
+        return Ok(Vec::new());
+    };
+    // Declination is measured from the celestial equator, the same in either zodiac
+    let declination = |jd: f64| -> Result<f64, String> {
+        Ok(calc_planet_position(transit_planet, jd, ZodiacMode::Tropical)?.declination)
+    };
+
+    let samples = sample(
+        transit_planet,
+        start_julian_day,
+        end_julian_day,
+        declination,
+    )?;
+    let mut windows = Vec::new();
+
+    for &(natal_point, natal_declination) in natal_declinations {
+        // Parallels match the natal declination, contra-parallels mirror it across the equator
+        for (aspect, target) in [
+            (DeclinationAspect::Parallel, natal_declination),
+            (DeclinationAspect::ContraParallel, -natal_declination),
+        ] {
+            let distances: Vec<(f64, f64)> =
+                samples.iter().map(|&(jd, d)| (jd, d - target)).collect();
+            windows.extend(orb_windows(
+                &distances,
+                orb,
+                |jd| Ok(declination(jd)? - target),
+                |enters_orb| {
+                    new_window(
+                        transit_planet,
+                        natal_point,
+                        TransitAspect::Declination(aspect),
+                        enters_orb,
+                    )
+                },
+            )?);
+        }
+    }
+
+    Ok(windows)
+}
+
+fn new_window(
+    transit_planet: Planet,
+    natal_point: ChartPoint,
+    aspect: TransitAspect,
+    enters_orb: Option<f64>,
+) -> TransitAspectWindow {
+    TransitAspectWindow {
+        transit_planet,
+        natal_point,
+        aspect,
+        enters_orb,
+        leaves_orb: None,
+        exact: Vec::new(),
+        closest_orb: f64::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::calculator::{date_to_julian_day, julian_day_to_date};
+    use chrono::NaiveDate;
+
+    fn julian_day(year: i32, month: u32, day: u32) -> f64 {
+        date_to_julian_day(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    fn conjunction_orb(orb: f64) -> OrbPolicy {
+        OrbPolicy {
+            aspect_orbs: [(AspectType::Conjunction, orb)].into_iter().collect(),
+            ..OrbPolicy::default()
+        }
+    }
+
+    fn conjunctions(
+        planet: Planet,
+        natal_longitude: f64,
+        orb: f64,
+        start: f64,
+        end: f64,
+    ) -> Vec<TransitAspectWindow> {
+        find_transit_aspect_windows(
+            planet,
+            &[(ChartPoint::Planet(Planet::Sun), natal_longitude)],
+            &[AspectType::Conjunction],
+            &conjunction_orb(orb),
+            start,
+            end,
+            ZodiacMode::Tropical,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_single_hit_window() {
+        let exact_jd = julian_day(2024, 6, 1);
+        let natal = calc_planet_position(Planet::Sun, exact_jd, ZodiacMode::Tropical)
+            .unwrap()
+            .longitude;
+        let windows = conjunctions(
+            Planet::Sun,
+            natal,
+            3.0,
+            julian_day(2024, 5, 1),
+            julian_day(2024, 7, 1),
+        );
+
+        assert_eq!(windows.len(), 1);
+        let window = &windows[0];
+        assert_eq!(window.exact.len(), 1);
+        assert!((window.exact[0] - exact_jd).abs() < 0.001);
+        // The Sun moves about a degree a day, so a 3° orb lasts about three days either side
+        let enters = window.enters_orb.unwrap();
+        let leaves = window.leaves_orb.unwrap();
+        assert!((exact_jd - enters - 3.0).abs() < 0.2);
+        assert!((leaves - exact_jd - 3.0).abs() < 0.2);
+        assert_eq!(window.closest_orb, 0.0);
+    }
+
+    #[test]
+    fn test_retrograde_triple_hit_window() {
+        // Saturn stationed retrograde near 7° Pisces in June 2023 and direct near 0° Pisces that November
+        let windows = conjunctions(
+            Planet::Saturn,
+            333.0,
+            8.0,
+            julian_day(2023, 1, 1),
+            julian_day(2024, 6, 1),
+        );
+
+        assert_eq!(windows.len(), 1);
+        let window = &windows[0];
+        assert_eq!(window.exact.len(), 3);
+        assert!(window.exact.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(window.enters_orb.unwrap() < window.exact[0]);
+        assert!(window.leaves_orb.unwrap() > window.exact[2]);
+    }
+
+    #[test]
+    fn test_window_between_two_samples() {
+        // With a 0.5° orb the Moon is in orb for under two hours, less than its 0.1 day sampling step
+        let windows = conjunctions(
+            Planet::Moon,
+            100.0,
+            0.5,
+            julian_day(2024, 1, 1),
+            julian_day(2024, 3, 1),
+        );
+
+        assert_eq!(windows.len(), 2);
+        for window in &windows {
+            assert_eq!(window.exact.len(), 1);
+            let enters = window.enters_orb.unwrap();
+            let leaves = window.leaves_orb.unwrap();
+            assert!(enters < window.exact[0] && window.exact[0] < leaves);
+            assert!(leaves - enters < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_parallel_windows() {
+        let natal = [(ChartPoint::Planet(Planet::Moon), 10.0)];
+        let (start, end) = (julian_day(2024, 1, 1), julian_day(2025, 1, 1));
+        assert!(find_transit_parallel_windows(
+            Planet::Sun,
+            &natal,
+            &OrbPolicy::default(),
+            start,
+            end
+        )
+        .unwrap()
+        .is_empty());
+
+        let orbs = OrbPolicy {
+            parallels: true,
+            ..OrbPolicy::default()
+        };
+        let windows =
+            find_transit_parallel_windows(Planet::Sun, &natal, &orbs, start, end).unwrap();
+
+        // The Sun reaches 10° north in April and August, and 10° south in February and October
+        let mut found: Vec<(String, TransitAspect)> = windows
+            .iter()
+            .map(|w| {
+                (
+                    julian_day_to_date(w.exact[0]).format("%m").to_string(),
+                    w.aspect,
+                )
+            })
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        let parallel = TransitAspect::Declination(DeclinationAspect::Parallel);
+        let contra_parallel = TransitAspect::Declination(DeclinationAspect::ContraParallel);
+        assert_eq!(
+            found,
+            vec![
+                ("02".to_string(), contra_parallel),
+                ("04".to_string(), parallel),
+                ("08".to_string(), parallel),
+                ("10".to_string(), contra_parallel),
+            ]
+        );
+
+        for window in &windows {
+            assert_eq!(window.exact.len(), 1);
+            let declination =
+                calc_planet_position(Planet::Sun, window.exact[0], ZodiacMode::Tropical)
+                    .unwrap()
+                    .declination;
+            assert!((declination.abs() - 10.0).abs() < 0.001);
+            // The Sun's declination changes by up to 0.4° a day, so a 1° orb lasts several days
+            assert!(
+                window.enters_orb.unwrap() < window.exact[0]
+                    && window.exact[0] < window.leaves_orb.unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Void-of-course Moon calculations
+//!
+//! The Moon is void of course from its last exact major aspect in a sign
+//! until it enters the next sign.
+
+use crate::models::{AspectType, Planet, ZodiacMode, ZodiacSign};
+
+use super::calculator::{angle_past, calc_planet_position, find_next_sign_ingress};
+
+/// Moon-planet separations (Moon minus planet) at which a major aspect is exact
+const MOON_ASPECT_ANGLES: [(f64, AspectType); 8] = [
+    (0.0, AspectType::Conjunction),
+    (60.0, AspectType::Sextile),
+    (90.0, AspectType::Square),
+    (120.0, AspectType::Trine),
+    (180.0, AspectType::Opposition),
+    (240.0, AspectType::Trine),
+    (270.0, AspectType::Square),
+    (300.0, AspectType::Sextile),
+];
+
+/// An exact major aspect from the Moon to a planet
+#[derive(Debug, Clone)]
+pub struct LunarAspect {
+    pub julian_day: f64,
+    pub planet: Planet,
+    pub aspect_type: AspectType,
+}
+
+/// A void-of-course period: from the Moon's last aspect in a sign until the next ingress
+#[derive(Debug, Clone)]
+pub struct VoidPeriod {
+    /// When the Moon goes void (its last aspect, or the sign ingress if it made none)
+    pub start: f64,
+    /// When the Moon enters the next sign
+    pub end: f64,
+    /// The aspect that starts the void period
+    pub last_aspect: Option<LunarAspect>,
+    /// Sign the Moon enters at the end of the period
+    pub next_sign: ZodiacSign,
+}
+
+/// Moon minus planet longitude, normalized to 0-360
+fn moon_separation(planet: Planet, julian_day: f64) -> Result<f64, String> {
+    let moon = calc_planet_position(Planet::Moon, julian_day, ZodiacMode::Tropical)?;
+    let other = calc_planet_position(planet, julian_day, ZodiacMode::Tropical)?;
+    Ok((moon.longitude - other.longitude).rem_euclid(360.0))
+}
+
+/// Find every exact major aspect the Moon makes to the given planets between two Julian Days
+/// The Moon always moves faster than the planets, so the separation only ever increases.
+pub fn find_moon_aspects(
+    start_julian_day: f64,
+    end_julian_day: f64,
+    planets: &[Planet],
+) -> Result<Vec<LunarAspect>, String> {
+    let step = 0.25; // Moon gains at most ~4° on any planet in 6 hours
+    let mut aspects = Vec::new();
+
+    for planet in planets {
+        let mut jd = start_julian_day;
+        let mut prev_sep = moon_separation(*planet, jd)?;
+
+        while jd < end_julian_day {
+            let next_jd = (jd + step).min(end_julian_day);
+            let sep = moon_separation(*planet, next_jd)?;
+
+            for (target, aspect_type) in MOON_ASPECT_ANGLES {
+                if angle_past(prev_sep, target) < 0.0 && angle_past(sep, target) >= 0.0 {
+                    // Found aspect, refine with binary search
+                    let mut low = jd;
+                    let mut high = next_jd;
+
+                    while high - low > 0.0001 {
+                        // ~9 seconds precision
+                        let mid = (low + high) / 2.0;
+                        if angle_past(moon_separation(*planet, mid)?, target) < 0.0 {
+                            low = mid;
+                        } else {
+                            high = mid;
+                        }
+                    }
+
+                    aspects.push(LunarAspect {
+                        julian_day: high,
+                        planet: *planet,
+                        aspect_type,
+                    });
+                }
+            }
+
+            prev_sep = sep;
+            jd = next_jd;
+        }
+    }
+
+    aspects.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+    Ok(aspects)
+}
+
+/// Find when the Moon entered the sign it occupies at the given Julian Day
+pub fn find_previous_moon_ingress(julian_day: f64, zodiac: ZodiacMode) -> Result<f64, String> {
+    // The Moon never spends more than ~2.7 days in a sign
+    let mut entry = julian_day - 3.0;
+    let mut jd = entry;
+
+    while let Some((ingress_jd, _)) = find_next_sign_ingress(Planet::Moon, jd, 3, zodiac)? {
+        if ingress_jd > julian_day {
+            break;
+        }
+        entry = ingress_jd;
+        jd = ingress_jd + 0.01;
+    }
+
+    Ok(entry)
+}
+
+/// Find the void-of-course period that ends with the Moon's next sign ingress after the given Julian Day
+/// The returned period may start after the given moment (the Moon is not void yet).
+pub fn find_void_of_course(
+    julian_day: f64,
+    planets: &[Planet],
+    zodiac: ZodiacMode,
+) -> Result<VoidPeriod, String> {
+    let sign_entry = find_previous_moon_ingress(julian_day, zodiac)?;
+    let (ingress_jd, next_sign) = find_next_sign_ingress(Planet::Moon, julian_day, 4, zodiac)?
+        .ok_or_else(|| "Could not find the Moon's next sign ingress".to_string())?;
+
+    let last_aspect = find_moon_aspects(sign_entry, ingress_jd, planets)?.pop();
+
+    Ok(VoidPeriod {
+        start: last_aspect
+            .as_ref()
+            .map(|a| a.julian_day)
+            .unwrap_or(sign_entry),
+        end: ingress_jd,
+        last_aspect,
+        next_sign,
+    })
+}
+
+/// Find the Moon's next exact major aspect after the given Julian Day
+pub fn find_next_moon_aspect(
+    julian_day: f64,
+    planets: &[Planet],
+) -> Result<Option<LunarAspect>, String> {
+    // Within any 3-day window the Moon aspects at least one planet
+    Ok(find_moon_aspects(julian_day, julian_day + 3.0, planets)?
+        .into_iter()
+        .next())
+}
+
+/// Find every void-of-course period overlapping the range between two Julian Days
+pub fn find_void_periods(
+    start_julian_day: f64,
+    end_julian_day: f64,
+    planets: &[Planet],
+    zodiac: ZodiacMode,
+) -> Result<Vec<VoidPeriod>, String> {
+    let mut periods = Vec::new();
+    let mut jd = start_julian_day;
+
+    loop {
+        let period = find_void_of_course(jd, planets, zodiac)?;
+        if period.start > end_julian_day {
+            break;
+        }
+
+        // Step just past the ingress so the next search starts in the new sign
+        jd = period.end + 0.01;
+        periods.push(period);
+    }
+
+    Ok(periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeris::init_ephemeris;
+
+    #[test]
+    fn test_void_of_course_period() {
+        init_ephemeris();
+        let jd = 2460662.5; // 2024-12-18 00:00 UT
+        let planets = [
+            Planet::Sun,
+            Planet::Mercury,
+            Planet::Venus,
+            Planet::Mars,
+            Planet::Jupiter,
+            Planet::Saturn,
+        ];
+
+        let period = find_void_of_course(jd, &planets, ZodiacMode::Tropical).unwrap();
+        assert!(period.start < period.end);
+        assert!(period.end > jd && period.end - jd < 3.0);
+
+        // The aspect that starts the void is exact, and no other aspect follows it in the sign
+        let aspect = period.last_aspect.unwrap();
+        let sep = moon_separation(aspect.planet, aspect.julian_day).unwrap();
+        let orb = MOON_ASPECT_ANGLES
+            .iter()
+            .map(|(target, _)| angle_past(sep, *target).abs())
+            .fold(f64::MAX, f64::min);
+        assert!(orb < 0.01);
+        assert!(
+            find_moon_aspects(period.start + 0.001, period.end, &planets)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
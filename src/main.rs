@@ -6,7 +6,7 @@ use anyhow::Result;
 use rmcp::{transport::stdio, ServiceExt};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
-use stellium::ephemeris::init_ephemeris;
+use stellium::ephemeris::{init_ephemeris, set_ephemeris_path, EPHEMERIS_PATH_ENV};
 use stellium::StelliumServer;
 
 #[tokio::main]
@@ -19,7 +19,19 @@ async fn main() -> Result<()> {
 
     tracing::info!("Starting Stellium MCP Server");
 
-    // Initialize Swiss Ephemeris
+    // Initialize Swiss Ephemeris, with the data files if a directory was given
+    let ephemeris_path = ephemeris_path_arg(std::env::args().skip(1))
+        .or_else(|| std::env::var(EPHEMERIS_PATH_ENV).ok());
+    match &ephemeris_path {
+        Some(path) => {
+            tracing::info!("Swiss Ephemeris files: {}", path);
+            for dir in std::env::split_paths(path).filter(|dir| !dir.is_dir()) {
+                tracing::warn!("Ephemeris path '{}' is not a directory", dir.display());
+            }
+        }
+        None => tracing::info!("No ephemeris path set, using the Moshier ephemeris (no asteroids)"),
+    }
+    set_ephemeris_path(ephemeris_path);
     init_ephemeris();
 
     // Create and run the MCP server
@@ -35,3 +47,41 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Ephemeris directory from `--ephe-path <dir>` (or `--ephe-path=<dir>`) in the command-line arguments
+fn ephemeris_path_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--ephe-path" {
+            return args.next();
+        }
+        if let Some(path) = arg.strip_prefix("--ephe-path=") {
+            return Some(path.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_ephemeris_path_arg() {
+        assert_eq!(
+            ephemeris_path_arg(args(&["--ephe-path", "/opt/ephe"])),
+            Some("/opt/ephe".to_string())
+        );
+        assert_eq!(
+            ephemeris_path_arg(args(&["-v", "--ephe-path=/opt/ephe"])),
+            Some("/opt/ephe".to_string())
+        );
+        // A trailing flag without a value gives no path
+        assert_eq!(ephemeris_path_arg(args(&["--ephe-path"])), None);
+        assert_eq!(ephemeris_path_arg(args(&["--other", "/opt/ephe"])), None);
+    }
+}
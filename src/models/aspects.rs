@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::closest_aspect;
+
 /// Types of astrological aspects
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AspectType {
     Conjunction,
@@ -15,6 +17,35 @@ pub enum AspectType {
     Quincunx,
     SemiSquare,
     Sesquiquadrate,
+    // Quintile family (5th harmonic)
+    Decile,
+    Quintile,
+    Tredecile,
+    Biquintile,
+    // Septile family (7th harmonic)
+    Septile,
+    Biseptile,
+    Triseptile,
+    // Novile family (9th harmonic)
+    Novile,
+    Binovile,
+    Quadnovile,
+}
+
+/// Harmonic families of aspects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AspectFamily {
+    /// Conjunction, sextile, square, trine, opposition
+    Major,
+    /// Semi-sextile, quincunx (12th harmonic), semi-square, sesquiquadrate (8th harmonic)
+    Minor,
+    /// Decile, quintile, tredecile, biquintile
+    Quintile,
+    /// Septile, biseptile, triseptile
+    Septile,
+    /// Novile, binovile, quadnovile
+    Novile,
 }
 
 impl AspectType {
@@ -23,12 +54,22 @@ impl AspectType {
         match self {
             AspectType::Conjunction => 0.0,
             AspectType::SemiSextile => 30.0,
+            AspectType::Decile => 36.0,
+            AspectType::Novile => 40.0,
             AspectType::SemiSquare => 45.0,
+            AspectType::Septile => 360.0 / 7.0,
             AspectType::Sextile => 60.0,
+            AspectType::Quintile => 72.0,
+            AspectType::Binovile => 80.0,
             AspectType::Square => 90.0,
+            AspectType::Biseptile => 720.0 / 7.0,
+            AspectType::Tredecile => 108.0,
             AspectType::Trine => 120.0,
             AspectType::Sesquiquadrate => 135.0,
+            AspectType::Biquintile => 144.0,
             AspectType::Quincunx => 150.0,
+            AspectType::Triseptile => 1080.0 / 7.0,
+            AspectType::Quadnovile => 160.0,
             AspectType::Opposition => 180.0,
         }
     }
@@ -45,19 +86,41 @@ impl AspectType {
             AspectType::Quincunx => 3.0,
             AspectType::SemiSquare => 2.0,
             AspectType::Sesquiquadrate => 2.0,
+            AspectType::Quintile | AspectType::Biquintile => 2.0,
+            AspectType::Decile | AspectType::Tredecile => 1.5,
+            AspectType::Septile | AspectType::Biseptile | AspectType::Triseptile => 1.5,
+            AspectType::Novile | AspectType::Binovile | AspectType::Quadnovile => 1.0,
         }
     }
 
     /// Whether this is a major aspect
     pub fn is_major(&self) -> bool {
-        matches!(
-            self,
+        self.family() == AspectFamily::Major
+    }
+
+    /// The harmonic family this aspect belongs to
+    pub fn family(&self) -> AspectFamily {
+        match self {
             AspectType::Conjunction
-                | AspectType::Sextile
-                | AspectType::Square
-                | AspectType::Trine
-                | AspectType::Opposition
-        )
+            | AspectType::Sextile
+            | AspectType::Square
+            | AspectType::Trine
+            | AspectType::Opposition => AspectFamily::Major,
+            AspectType::SemiSextile
+            | AspectType::Quincunx
+            | AspectType::SemiSquare
+            | AspectType::Sesquiquadrate => AspectFamily::Minor,
+            AspectType::Decile
+            | AspectType::Quintile
+            | AspectType::Tredecile
+            | AspectType::Biquintile => AspectFamily::Quintile,
+            AspectType::Septile | AspectType::Biseptile | AspectType::Triseptile => {
+                AspectFamily::Septile
+            }
+            AspectType::Novile | AspectType::Binovile | AspectType::Quadnovile => {
+                AspectFamily::Novile
+            }
+        }
     }
 
     /// Get all major aspects
@@ -71,20 +134,39 @@ impl AspectType {
         ]
     }
 
-    /// Get all aspects including minor ones
+    /// Get all aspects including minor ones and every harmonic family, ordered by angle
     pub fn all_aspects() -> &'static [AspectType] {
         &[
             AspectType::Conjunction,
             AspectType::SemiSextile,
+            AspectType::Decile,
+            AspectType::Novile,
             AspectType::SemiSquare,
+            AspectType::Septile,
             AspectType::Sextile,
+            AspectType::Quintile,
+            AspectType::Binovile,
             AspectType::Square,
+            AspectType::Biseptile,
+            AspectType::Tredecile,
             AspectType::Trine,
             AspectType::Sesquiquadrate,
+            AspectType::Biquintile,
             AspectType::Quincunx,
+            AspectType::Triseptile,
+            AspectType::Quadnovile,
             AspectType::Opposition,
         ]
     }
+
+    /// Get every aspect in the given families, ordered by angle
+    pub fn aspects_in_families(families: &[AspectFamily]) -> Vec<AspectType> {
+        Self::all_aspects()
+            .iter()
+            .filter(|aspect| families.contains(&aspect.family()))
+            .copied()
+            .collect()
+    }
 }
 
 impl fmt::Display for AspectType {
@@ -92,19 +174,43 @@ impl fmt::Display for AspectType {
         let name = match self {
             AspectType::Conjunction => "conjunction",
             AspectType::SemiSextile => "semi-sextile",
+            AspectType::Decile => "decile",
+            AspectType::Novile => "novile",
             AspectType::SemiSquare => "semi-square",
+            AspectType::Septile => "septile",
             AspectType::Sextile => "sextile",
+            AspectType::Quintile => "quintile",
+            AspectType::Binovile => "binovile",
             AspectType::Square => "square",
+            AspectType::Biseptile => "biseptile",
+            AspectType::Tredecile => "tredecile",
             AspectType::Trine => "trine",
             AspectType::Sesquiquadrate => "sesquiquadrate",
+            AspectType::Biquintile => "biquintile",
             AspectType::Quincunx => "quincunx",
+            AspectType::Triseptile => "triseptile",
+            AspectType::Quadnovile => "quadnovile",
             AspectType::Opposition => "opposition",
         };
         write!(f, "{}", name)
     }
 }
 
-/// Check if two positions form an aspect
+impl fmt::Display for AspectFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AspectFamily::Major => "major",
+            AspectFamily::Minor => "minor",
+            AspectFamily::Quintile => "quintile",
+            AspectFamily::Septile => "septile",
+            AspectFamily::Novile => "novile",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Check if two positions form an aspect, using the default orbs
+/// Returns the closest aspect within orb.
 pub fn find_aspect(
     longitude1: f64,
     longitude2: f64,
@@ -116,20 +222,138 @@ pub fn find_aspect(
         AspectType::major_aspects()
     };
 
-    // Calculate the shortest angular distance
-    let diff = (longitude1 - longitude2).abs();
-    let angular_distance = if diff > 180.0 { 360.0 - diff } else { diff };
+    closest_aspect(longitude1, longitude2, aspects, |aspect| {
+        aspect.default_orb()
+    })
+}
+
+/// Aspects measured in declination rather than longitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclinationAspect {
+    /// Same declination on the same side of the celestial equator (read like a conjunction)
+    Parallel,
+    /// Same declination on opposite sides of the equator (read like an opposition)
+    ContraParallel,
+}
+
+impl DeclinationAspect {
+    /// Default orb in degrees of declination
+    pub fn default_orb() -> f64 {
+        1.0
+    }
+}
+
+impl fmt::Display for DeclinationAspect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeclinationAspect::Parallel => write!(f, "Parallel"),
+            DeclinationAspect::ContraParallel => write!(f, "Contra-Parallel"),
+        }
+    }
+}
+
+/// Check if two declinations are parallel or contra-parallel within the orb
+/// Near the equator both can apply; the closer one is returned.
+pub fn find_declination_aspect(
+    declination1: f64,
+    declination2: f64,
+    orb: f64,
+) -> Option<(DeclinationAspect, f64)> {
+    [
+        (
+            DeclinationAspect::Parallel,
+            (declination1 - declination2).abs(),
+        ),
+        (
+            DeclinationAspect::ContraParallel,
+            (declination1 + declination2).abs(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, distance)| *distance <= orb)
+    .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// A parallel or contra-parallel between two points
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ParallelAspect {
+    pub point1: String,
+    pub point2: String,
+    pub aspect: DeclinationAspect,
+    /// Difference from exact, in degrees of declination
+    pub orb: f64,
+}
+
+impl ParallelAspect {
+    pub fn new(point1: String, point2: String, aspect: DeclinationAspect, orb: f64) -> Self {
+        Self {
+            point1,
+            point2,
+            aspect,
+            orb: (orb * 100.0).round() / 100.0,
+        }
+    }
+}
+
+/// Whether an aspect is building toward exact or moving past it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AspectMotion {
+    Applying,
+    Separating,
+}
+
+impl AspectMotion {
+    /// Motion from the rate at which the orb changes
+    pub fn from_orb_rate(orb_rate: f64) -> Self {
+        if orb_rate < 0.0 {
+            AspectMotion::Applying
+        } else {
+            AspectMotion::Separating
+        }
+    }
+}
+
+/// Applying/separating motion of an aspect and how fast its orb changes, as reported alongside it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct OrbMotion {
+    pub motion: AspectMotion,
+    /// Degrees per day, rounded to 0.001
+    pub rate: f64,
+}
 
-    for aspect in aspects {
-        let target_angle = aspect.angle();
-        let orb = (angular_distance - target_angle).abs();
+impl OrbMotion {
+    pub fn from_orb_rate(orb_rate: f64) -> Self {
+        Self {
+            motion: AspectMotion::from_orb_rate(orb_rate),
+            rate: (orb_rate.abs() * 1000.0).round() / 1000.0,
+        }
+    }
+}
 
-        if orb <= aspect.default_orb() {
-            return Some((*aspect, orb));
+impl fmt::Display for AspectMotion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AspectMotion::Applying => write!(f, "applying"),
+            AspectMotion::Separating => write!(f, "separating"),
         }
     }
+}
 
-    None
+/// Rate (degrees/day) at which an aspect's orb changes, from both bodies' speeds in longitude.
+/// Negative while the aspect is applying.
+pub fn orb_rate(
+    longitude1: f64,
+    speed1: f64,
+    longitude2: f64,
+    speed2: f64,
+    aspect_type: AspectType,
+) -> f64 {
+    let diff = (longitude1 - longitude2 + 180.0).rem_euclid(360.0) - 180.0;
+    // The shortest arc widens when the difference moves away from 0°
+    let separation_rate = diff.signum() * (speed1 - speed2);
+    (diff.abs() - aspect_type.angle()).signum() * separation_rate
 }
 
 /// An aspect between two celestial bodies
@@ -141,6 +365,12 @@ pub struct Aspect {
     pub orb: f64,
     /// Whether the aspect is within 1 degree of exact
     pub is_exact: bool,
+    /// Applying (building toward exact) or separating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motion: Option<AspectMotion>,
+    /// How fast the orb is changing (degrees/day)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
 }
 
 impl Aspect {
@@ -150,6 +380,160 @@ impl Aspect {
             aspect_type,
             is_exact: orb < 1.0,
             orb,
+            motion: None,
+            rate: None,
         }
     }
+
+    /// Add applying/separating motion from the rate at which the orb changes
+    pub fn with_orb_rate(mut self, orb_rate: f64) -> Self {
+        let orb_motion = OrbMotion::from_orb_rate(orb_rate);
+        self.motion = Some(orb_motion.motion);
+        self.rate = Some(orb_motion.rate);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn motion(
+        longitude1: f64,
+        speed1: f64,
+        longitude2: f64,
+        aspect_type: AspectType,
+    ) -> AspectMotion {
+        AspectMotion::from_orb_rate(orb_rate(longitude1, speed1, longitude2, 0.0, aspect_type))
+    }
+
+    #[test]
+    fn test_applying_and_separating() {
+        // Direct motion closes on the natal point from behind and moves away once past it
+        assert_eq!(
+            motion(95.0, 1.0, 100.0, AspectType::Conjunction),
+            AspectMotion::Applying
+        );
+        assert_eq!(
+            motion(105.0, 1.0, 100.0, AspectType::Conjunction),
+            AspectMotion::Separating
+        );
+        assert_eq!(
+            motion(5.0, 1.0, 100.0, AspectType::Square),
+            AspectMotion::Applying
+        );
+        assert_eq!(
+            motion(15.0, 1.0, 100.0, AspectType::Square),
+            AspectMotion::Separating
+        );
+        // Across 0° Aries
+        assert_eq!(
+            motion(358.0, 1.0, 2.0, AspectType::Conjunction),
+            AspectMotion::Applying
+        );
+        assert_eq!(
+            motion(2.0, 1.0, 358.0, AspectType::Conjunction),
+            AspectMotion::Separating
+        );
+    }
+
+    #[test]
+    fn test_retrograde_motion_flips_the_sign() {
+        assert_eq!(
+            motion(95.0, -0.5, 100.0, AspectType::Conjunction),
+            AspectMotion::Separating
+        );
+        assert_eq!(
+            motion(105.0, -0.5, 100.0, AspectType::Conjunction),
+            AspectMotion::Applying
+        );
+        assert_eq!(
+            motion(5.0, -0.5, 100.0, AspectType::Square),
+            AspectMotion::Separating
+        );
+        assert_eq!(
+            motion(15.0, -0.5, 100.0, AspectType::Square),
+            AspectMotion::Applying
+        );
+        // Both bodies moving: only the relative speed counts
+        let rate = orb_rate(95.0, 0.5, 100.0, 1.0, AspectType::Conjunction);
+        assert_eq!(AspectMotion::from_orb_rate(rate), AspectMotion::Separating);
+    }
+
+    #[test]
+    fn test_orb_motion_rounds_the_rate() {
+        let orb_motion = OrbMotion::from_orb_rate(-0.98765);
+        assert_eq!(orb_motion.motion, AspectMotion::Applying);
+        assert_eq!(orb_motion.rate, 0.988);
+
+        let aspect = Aspect::new("Sun".to_string(), AspectType::Trine, 2.0).with_orb_rate(0.12345);
+        assert_eq!(aspect.motion, Some(AspectMotion::Separating));
+        assert_eq!(aspect.rate, Some(0.123));
+    }
+
+    #[test]
+    fn test_aspect_families() {
+        assert!(AspectType::major_aspects()
+            .iter()
+            .all(|a| a.family() == AspectFamily::Major));
+        assert_eq!(
+            AspectType::aspects_in_families(&[AspectFamily::Minor]),
+            vec![
+                AspectType::SemiSextile,
+                AspectType::SemiSquare,
+                AspectType::Sesquiquadrate,
+                AspectType::Quincunx
+            ]
+        );
+        assert_eq!(
+            AspectType::aspects_in_families(&[AspectFamily::Quintile, AspectFamily::Novile]),
+            vec![
+                AspectType::Decile,
+                AspectType::Novile,
+                AspectType::Quintile,
+                AspectType::Binovile,
+                AspectType::Tredecile,
+                AspectType::Biquintile,
+                AspectType::Quadnovile,
+            ]
+        );
+        // Every aspect belongs to exactly one family
+        let families = [
+            AspectFamily::Major,
+            AspectFamily::Minor,
+            AspectFamily::Quintile,
+            AspectFamily::Septile,
+            AspectFamily::Novile,
+        ];
+        let total: usize = families
+            .iter()
+            .map(|f| AspectType::aspects_in_families(&[*f]).len())
+            .sum();
+        assert_eq!(total, AspectType::all_aspects().len());
+        assert!(!AspectType::Quincunx.is_major());
+    }
+
+    #[test]
+    fn test_minor_aspect_angles() {
+        assert_eq!(AspectType::SemiSextile.angle(), 30.0);
+        assert_eq!(AspectType::SemiSquare.angle(), 45.0);
+        assert_eq!(AspectType::Sesquiquadrate.angle(), 135.0);
+        assert_eq!(AspectType::Quincunx.angle(), 150.0);
+        assert_eq!(AspectType::Decile.angle(), 36.0);
+        assert_eq!(AspectType::Tredecile.angle(), 108.0);
+        assert_eq!(AspectType::Binovile.angle(), 80.0);
+        assert!((AspectType::Triseptile.angle() - 154.2857).abs() < 0.0001);
+
+        let angles: Vec<f64> = AspectType::all_aspects()
+            .iter()
+            .map(|a| a.angle())
+            .collect();
+        assert!(angles.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // A septile 51°26' apart, found across 0° Aries
+        let (aspect, orb) =
+            closest_aspect(10.0, 318.6, AspectType::all_aspects(), |a| a.default_orb()).unwrap();
+        assert_eq!(aspect, AspectType::Septile);
+        assert!(orb < 0.1);
+    }
 }
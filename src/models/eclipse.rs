@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{AspectType, LifeArea, ZodiacPosition};
+
+/// Solar or lunar eclipse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EclipseKind {
+    Solar,
+    Lunar,
+}
+
+impl fmt::Display for EclipseKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EclipseKind::Solar => "Solar",
+            EclipseKind::Lunar => "Lunar",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Eclipse type, as seen from the most favorable place on Earth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EclipseType {
+    Total,
+    Annular,
+    /// Annular along part of the path and total along the rest
+    Hybrid,
+    Partial,
+    /// The Moon passes through the Earth's penumbra only (lunar eclipses)
+    Penumbral,
+}
+
+impl fmt::Display for EclipseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EclipseType::Total => "Total",
+            EclipseType::Annular => "Annular",
+            EclipseType::Hybrid => "Hybrid",
+            EclipseType::Partial => "Partial",
+            EclipseType::Penumbral => "Penumbral",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Which way to search for eclipses from a date
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchDirection {
+    #[default]
+    Next,
+    Previous,
+}
+
+/// An eclipse with its phase times (exact ISO-8601 timestamps in UTC)
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct EclipseSummary {
+    pub kind: EclipseKind,
+    #[serde(rename = "type")]
+    pub eclipse_type: EclipseType,
+    /// Whether the shadow axis touches the Earth (solar eclipses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub central: Option<bool>,
+    /// Time of greatest eclipse
+    pub maximum: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_local: Option<String>,
+    /// First and last contact anywhere on Earth (penumbral contacts for lunar eclipses)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    /// Umbral phase of a lunar eclipse
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_begin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_end: Option<String>,
+    /// Total or annular phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub totality_begin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub totality_end: Option<String>,
+    pub magnitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penumbral_magnitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saros_series: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saros_member: Option<i32>,
+    /// Position of the eclipsed Sun or Moon at greatest eclipse
+    pub position: ZodiacPosition,
+}
+
+impl EclipseSummary {
+    /// Short description, e.g. "Total Solar Eclipse"
+    pub fn title(&self) -> String {
+        format!("{} {} Eclipse", self.eclipse_type, self.kind)
+    }
+}
+
+/// An eclipse contact at the observer's location
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct LocalEclipseContact {
+    /// e.g. "first_contact" (solar) or "partial_begin" (lunar)
+    pub contact: String,
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_time: Option<String>,
+    /// Apparent altitude of the Sun or Moon in degrees
+    pub altitude: f64,
+    /// Whether the Sun or Moon is above the horizon
+    pub visible: bool,
+}
+
+/// How an eclipse looks from one place
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct LocalEclipseCircumstances {
+    pub visible: bool,
+    /// Type as seen from the place (a total solar eclipse is partial outside the path)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub eclipse_type: Option<EclipseType>,
+    /// Time of greatest eclipse at the place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magnitude: Option<f64>,
+    /// Fraction of the Sun's disc covered (solar eclipses)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub obscuration: Option<f64>,
+    /// Apparent altitude and azimuth (degrees from north) of the Sun or Moon at greatest eclipse
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azimuth: Option<f64>,
+    pub contacts: Vec<LocalEclipseContact>,
+}
+
+/// A natal point an eclipse falls on
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct NatalEclipseContact {
+    pub point: String,
+    pub aspect: AspectType,
+    pub orb: f64,
+    pub natal_position: ZodiacPosition,
+    /// Natal house of the point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_area: Option<LifeArea>,
+}
+
+/// An eclipse placed in a natal chart
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct NatalEclipse {
+    #[serde(flatten)]
+    pub eclipse: EclipseSummary,
+    /// Natal house the eclipse degree falls in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_area: Option<LifeArea>,
+    /// Natal points conjunct or opposite the eclipse degree, closest first
+    pub contacts: Vec<NatalEclipseContact>,
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use super::ZodiacSign;
+use super::{DateRange, EclipseSummary, Planet, ZodiacSign};
 
 /// Request to get lunar information
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
@@ -82,30 +82,137 @@ pub struct LunarPhase {
     pub moon_degree: f64,
 }
 
+/// Which planets count when deciding whether the Moon is void of course
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VoidOfCourseRule {
+    /// The seven visible planets (Sun through Saturn)
+    Traditional,
+    /// The traditional planets plus Uranus, Neptune and Pluto
+    #[default]
+    Modern,
+}
+
+impl VoidOfCourseRule {
+    /// Planets the Moon must aspect to stay out of void
+    pub fn planets(&self) -> &'static [Planet] {
+        match self {
+            VoidOfCourseRule::Traditional => &[
+                Planet::Sun,
+                Planet::Mercury,
+                Planet::Venus,
+                Planet::Mars,
+                Planet::Jupiter,
+                Planet::Saturn,
+            ],
+            VoidOfCourseRule::Modern => &[
+                Planet::Sun,
+                Planet::Mercury,
+                Planet::Venus,
+                Planet::Mars,
+                Planet::Jupiter,
+                Planet::Saturn,
+                Planet::Uranus,
+                Planet::Neptune,
+                Planet::Pluto,
+            ],
+        }
+    }
+}
+
 /// Void-of-course moon information
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct VoidOfCourse {
     pub is_void: bool,
+    /// Planets considered (traditional or modern)
+    pub rule: VoidOfCourseRule,
+    /// The Moon's last major aspect before it leaves its sign (e.g., "Moon trine Saturn")
+    pub last_aspect: Option<String>,
+    /// Exact times are ISO-8601 timestamps in UTC
     pub last_aspect_time: Option<String>,
     pub next_aspect_time: Option<String>,
     pub enters_void_at: Option<String>,
     pub exits_void_at: Option<String>,
+    /// The same times in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_aspect_time_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_aspect_time_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enters_void_at_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exits_void_at_local: Option<String>,
+    /// Sign the Moon enters when the void period ends
+    pub next_sign: Option<ZodiacSign>,
 }
 
-/// Lunar cycle dates
+/// Lunar cycle times (exact ISO-8601 timestamps in UTC)
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct LunarCycle {
     pub new_moon: String,
     pub full_moon: String,
     pub next_new_moon: String,
     pub next_full_moon: String,
+    /// The same times in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_moon_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_moon_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_new_moon_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_full_moon_local: Option<String>,
+    /// The next solar and lunar eclipses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_solar_eclipse: Option<EclipseSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_lunar_eclipse: Option<EclipseSummary>,
 }
 
 /// Response for lunar information
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct GetLunarInfoResponse {
     pub date: String,
+    /// Exact moment (UTC) the phase and void-of-course status refer to
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Zodiac the Moon's sign is expressed in
+    pub zodiac: String,
     pub lunar_phase: LunarPhase,
     pub void_of_course: VoidOfCourse,
     pub lunar_cycle: LunarCycle,
 }
+
+/// A single void-of-course period
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct VoidOfCoursePeriod {
+    /// When the Moon goes void (UTC)
+    pub start: String,
+    /// When the Moon enters the next sign (UTC)
+    pub end: String,
+    /// Start in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_local: Option<String>,
+    /// End in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_local: Option<String>,
+    pub duration_hours: f64,
+    /// The aspect that starts the void (e.g., "Moon square Saturn")
+    pub last_aspect: Option<String>,
+    /// Sign the Moon enters when the void ends
+    pub next_sign: ZodiacSign,
+}
+
+/// Response for the void-of-course calendar
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct GetVoidOfCourseCalendarResponse {
+    pub period: DateRange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    pub rule: VoidOfCourseRule,
+    pub zodiac: String,
+    pub void_periods: Vec<VoidOfCoursePeriod>,
+}
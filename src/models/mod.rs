@@ -1,13 +1,19 @@
+pub mod aspects;
+pub mod eclipse;
+pub mod life_area;
+pub mod lunar;
 pub mod natal_chart;
+pub mod orbs;
+pub mod patterns;
 pub mod transits;
-pub mod lunar;
-pub mod aspects;
 pub mod zodiac;
-pub mod life_area;
 
+pub use aspects::*;
+pub use eclipse::*;
+pub use life_area::*;
+pub use lunar::*;
 pub use natal_chart::*;
+pub use orbs::*;
+pub use patterns::*;
 pub use transits::*;
-pub use lunar::*;
-pub use aspects::*;
 pub use zodiac::*;
-pub use life_area::*;
@@ -1,8 +1,12 @@
 use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
-use super::{Planet, ZodiacPosition};
+use super::{
+    orb_rate, AdditionalPoint, AspectMotion, AspectType, Ayanamsa, ChartPoint, OrbMotion,
+    OrbPolicy, ParallelAspect, Planet, ZodiacMode, ZodiacPosition, ZodiacType,
+};
 
 /// Request to store a natal chart
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
@@ -34,9 +38,42 @@ pub struct StoreNatalChartRequest {
     /// Timezone identifier (e.g., "America/Panama")
     #[schemars(description = "Timezone identifier (e.g., 'America/Panama')")]
     pub timezone: String,
+
+    /// Zodiac to calculate the chart in
+    #[schemars(description = "Zodiac: 'tropical' (default) or 'sidereal'")]
+    #[serde(default)]
+    pub zodiac: Option<ZodiacType>,
+
+    /// Ayanamsa for the sidereal zodiac
+    #[schemars(description = "Ayanamsa for the sidereal zodiac (default: 'lahiri')")]
+    #[serde(default)]
+    pub ayanamsa: Option<Ayanamsa>,
+
+    /// House system to calculate cusps with
+    #[schemars(description = "House system (default: 'placidus')")]
+    #[serde(default)]
+    pub house_system: Option<HouseSystem>,
+
+    /// What to do when the house system is undefined at the birth latitude
+    #[schemars(
+        description = "Fallback when the house system fails at polar latitudes (default: 'porphyry')"
+    )]
+    #[serde(default)]
+    pub polar_fallback: Option<HouseFallbackPolicy>,
+
+    /// How to resolve a birth time repeated when clocks fall back
+    #[schemars(
+        description = "Which occurrence of a repeated fall-back hour to use: 'earlier', 'later' or 'reject' (default)"
+    )]
+    #[serde(default)]
+    pub ambiguous_time: Option<AmbiguousTimePolicy>,
 }
 
 impl StoreNatalChartRequest {
+    pub fn zodiac_mode(&self) -> ZodiacMode {
+        ZodiacMode::from_options(self.zodiac, self.ayanamsa).unwrap_or_default()
+    }
+
     pub fn parse_date(&self) -> Result<NaiveDate, chrono::ParseError> {
         NaiveDate::parse_from_str(&self.birth_date, "%Y-%m-%d")
     }
@@ -46,6 +83,195 @@ impl StoreNatalChartRequest {
     }
 }
 
+/// House systems supported by Swiss Ephemeris
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HouseSystem {
+    #[default]
+    Placidus,
+    Koch,
+    Porphyry,
+    Regiomontanus,
+    Campanus,
+    Equal,
+    WholeSign,
+    Alcabitius,
+    Morinus,
+    Topocentric,
+    Meridian,
+    Azimuthal,
+    Vehlow,
+    KrusinskiPisa,
+    Apc,
+    EqualMc,
+    EqualAries,
+    CarterPoliEquatorial,
+    PullenSd,
+    PullenSr,
+    Sunshine,
+    Sripati,
+}
+
+impl HouseSystem {
+    /// Get all house systems
+    pub fn all() -> &'static [HouseSystem] {
+        &[
+            HouseSystem::Placidus,
+            HouseSystem::Koch,
+            HouseSystem::Porphyry,
+            HouseSystem::Regiomontanus,
+            HouseSystem::Campanus,
+            HouseSystem::Equal,
+            HouseSystem::WholeSign,
+            HouseSystem::Alcabitius,
+            HouseSystem::Morinus,
+            HouseSystem::Topocentric,
+            HouseSystem::Meridian,
+            HouseSystem::Azimuthal,
+            HouseSystem::Vehlow,
+            HouseSystem::KrusinskiPisa,
+            HouseSystem::Apc,
+            HouseSystem::EqualMc,
+            HouseSystem::EqualAries,
+            HouseSystem::CarterPoliEquatorial,
+            HouseSystem::PullenSd,
+            HouseSystem::PullenSr,
+            HouseSystem::Sunshine,
+            HouseSystem::Sripati,
+        ]
+    }
+
+    /// Get Swiss Ephemeris house system code
+    pub fn code(&self) -> i8 {
+        let code = match self {
+            HouseSystem::Placidus => b'P',
+            HouseSystem::Koch => b'K',
+            HouseSystem::Porphyry => b'O',
+            HouseSystem::Regiomontanus => b'R',
+            HouseSystem::Campanus => b'C',
+            HouseSystem::Equal => b'E',
+            HouseSystem::WholeSign => b'W',
+            HouseSystem::Alcabitius => b'B',
+            HouseSystem::Morinus => b'M',
+            HouseSystem::Topocentric => b'T',
+            HouseSystem::Meridian => b'X',
+            HouseSystem::Azimuthal => b'H',
+            HouseSystem::Vehlow => b'V',
+            HouseSystem::KrusinskiPisa => b'U',
+            HouseSystem::Apc => b'Y',
+            HouseSystem::EqualMc => b'D',
+            HouseSystem::EqualAries => b'N',
+            HouseSystem::CarterPoliEquatorial => b'F',
+            HouseSystem::PullenSd => b'L',
+            HouseSystem::PullenSr => b'Q',
+            HouseSystem::Sunshine => b'I',
+            HouseSystem::Sripati => b'S',
+        };
+        code as i8
+    }
+
+    /// Get house system from Swiss Ephemeris code
+    pub fn from_code(code: i8) -> Option<Self> {
+        Self::all().iter().copied().find(|h| h.code() == code)
+    }
+
+    /// Get house system from its display name (as stored in `HouseCusps.system`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|h| h.name() == name)
+    }
+
+    /// Display name of this house system
+    pub fn name(&self) -> &'static str {
+        match self {
+            HouseSystem::Placidus => "Placidus",
+            HouseSystem::Koch => "Koch",
+            HouseSystem::Porphyry => "Porphyry",
+            HouseSystem::Regiomontanus => "Regiomontanus",
+            HouseSystem::Campanus => "Campanus",
+            HouseSystem::Equal => "Equal",
+            HouseSystem::WholeSign => "Whole Sign",
+            HouseSystem::Alcabitius => "Alcabitius",
+            HouseSystem::Morinus => "Morinus",
+            HouseSystem::Topocentric => "Topocentric",
+            HouseSystem::Meridian => "Meridian",
+            HouseSystem::Azimuthal => "Azimuthal",
+            HouseSystem::Vehlow => "Vehlow Equal",
+            HouseSystem::KrusinskiPisa => "Krusinski-Pisa",
+            HouseSystem::Apc => "APC",
+            HouseSystem::EqualMc => "Equal (MC)",
+            HouseSystem::EqualAries => "Equal (Aries)",
+            HouseSystem::CarterPoliEquatorial => "Carter Poli-Equatorial",
+            HouseSystem::PullenSd => "Pullen SD",
+            HouseSystem::PullenSr => "Pullen SR",
+            HouseSystem::Sunshine => "Sunshine",
+            HouseSystem::Sripati => "Sripati",
+        }
+    }
+}
+
+impl fmt::Display for HouseSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Fallback policy for house systems that are undefined at polar latitudes
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HouseFallbackPolicy {
+    /// Switch to Porphyry (the Swiss Ephemeris default)
+    #[default]
+    Porphyry,
+    /// Switch to Whole Sign houses
+    WholeSign,
+    /// Switch to Equal houses
+    Equal,
+    /// Fail instead of switching systems
+    Reject,
+}
+
+impl HouseFallbackPolicy {
+    /// House system to switch to, or None when the chart should be rejected
+    pub fn fallback_system(&self) -> Option<HouseSystem> {
+        match self {
+            HouseFallbackPolicy::Porphyry => Some(HouseSystem::Porphyry),
+            HouseFallbackPolicy::WholeSign => Some(HouseSystem::WholeSign),
+            HouseFallbackPolicy::Equal => Some(HouseSystem::Equal),
+            HouseFallbackPolicy::Reject => None,
+        }
+    }
+}
+
+/// How to resolve a local birth time that occurs twice when clocks fall back
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AmbiguousTimePolicy {
+    /// The first occurrence (still on daylight saving time)
+    Earlier,
+    /// The second occurrence (after clocks went back)
+    Later,
+    /// Fail and ask for an explicit choice
+    #[default]
+    Reject,
+}
+
+/// Record of a house system that had to be replaced by its fallback
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct HouseSystemFallback {
+    /// House system originally requested
+    pub requested: HouseSystem,
+    /// House system actually used for the cusps
+    pub used: HouseSystem,
+    /// Why the requested system could not be used
+    pub reason: String,
+}
+
 /// House cusp data for a natal chart
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HouseCusps {
@@ -63,6 +289,53 @@ pub struct PlanetPosition {
     pub house: u8,
     /// Whether the planet is retrograde
     pub is_retrograde: bool,
+    /// Speed in longitude at birth (degrees/day)
+    #[serde(default)]
+    pub speed: Option<f64>,
+    /// Declination at birth (degrees, negative = south)
+    #[serde(default)]
+    pub declination: Option<f64>,
+    /// Right ascension at birth (degrees)
+    #[serde(default)]
+    pub right_ascension: Option<f64>,
+}
+
+/// An aspect between two points of the same natal chart
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct NatalAspect {
+    /// First point (the one listed earlier in the chart: planets, then Ascendant, Midheaven, Vertex)
+    pub point1: String,
+    /// Second point
+    pub point2: String,
+    pub aspect: AspectType,
+    /// Orb in degrees
+    pub orb: f64,
+    /// Whether the aspect was applying or separating at birth (None without natal speeds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motion: Option<AspectMotion>,
+    /// How fast the orb was changing at birth, in degrees per day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+}
+
+impl NatalAspect {
+    fn new(
+        point1: String,
+        point2: String,
+        aspect: AspectType,
+        orb: f64,
+        orb_rate: Option<f64>,
+    ) -> Self {
+        let orb_motion = orb_rate.map(OrbMotion::from_orb_rate);
+        Self {
+            point1,
+            point2,
+            aspect,
+            orb: (orb * 100.0).round() / 100.0,
+            motion: orb_motion.map(|m| m.motion),
+            rate: orb_motion.map(|m| m.rate),
+        }
+    }
 }
 
 /// Stored natal chart with calculated positions
@@ -76,6 +349,18 @@ pub struct NatalChart {
     pub longitude: f64,
     pub timezone: String,
 
+    /// Zodiac the positions are expressed in
+    #[serde(default)]
+    pub zodiac: ZodiacType,
+
+    /// Ayanamsa used when the zodiac is sidereal
+    #[serde(default)]
+    pub ayanamsa: Option<Ayanamsa>,
+
+    /// Ayanamsa value in degrees at the moment of birth
+    #[serde(default)]
+    pub ayanamsa_value: Option<f64>,
+
     /// Planetary positions at birth (legacy format for compatibility)
     pub planets: HashMap<Planet, ZodiacPosition>,
 
@@ -96,10 +381,43 @@ pub struct NatalChart {
     /// All 12 house cusps
     #[serde(default)]
     pub houses: Option<HouseCusps>,
+
+    /// Set when the requested house system failed and a fallback was used
+    #[serde(default)]
+    pub house_system_fallback: Option<HouseSystemFallback>,
+
+    /// Policy used to resolve an ambiguous birth time
+    #[serde(default)]
+    pub ambiguous_time: Option<AmbiguousTimePolicy>,
+
+    /// UTC offset in effect at birth (e.g., "-04:00")
+    #[serde(default)]
+    pub utc_offset: Option<String>,
+
+    /// Aspects between the chart's own planets and angles
+    #[serde(default)]
+    pub aspects: Vec<NatalAspect>,
+
+    /// Part of Fortune (Ascendant + Moon - Sun by day, reversed by night)
+    #[serde(default)]
+    pub part_of_fortune: Option<ZodiacPosition>,
+
+    /// Part of Spirit (Ascendant + Sun - Moon by day, reversed by night)
+    #[serde(default)]
+    pub part_of_spirit: Option<ZodiacPosition>,
+
+    /// Whether the Sun was above the horizon at birth
+    #[serde(default)]
+    pub is_day_chart: Option<bool>,
+
+    /// Obliquity of the ecliptic at birth (the Sun's greatest declination)
+    #[serde(default)]
+    pub obliquity: Option<f64>,
 }
 
 impl NatalChart {
     pub fn new(request: &StoreNatalChartRequest) -> Self {
+        let zodiac = request.zodiac_mode();
         Self {
             name: request.name.clone(),
             birth_date: request.birth_date.clone(),
@@ -108,15 +426,50 @@ impl NatalChart {
             latitude: request.latitude,
             longitude: request.longitude,
             timezone: request.timezone.clone(),
+            zodiac: zodiac.zodiac_type(),
+            ayanamsa: zodiac.ayanamsa(),
+            ayanamsa_value: None,
             planets: HashMap::new(),
             planet_positions: HashMap::new(),
             ascendant: None,
             midheaven: None,
             vertex: None,
             houses: None,
+            house_system_fallback: None,
+            ambiguous_time: request.ambiguous_time,
+            utc_offset: None,
+            aspects: Vec::new(),
+            part_of_fortune: None,
+            part_of_spirit: None,
+            is_day_chart: None,
+            obliquity: None,
         }
     }
 
+    /// Zodiac the stored positions are expressed in
+    pub fn zodiac_mode(&self) -> ZodiacMode {
+        match self.zodiac {
+            ZodiacType::Tropical => ZodiacMode::Tropical,
+            ZodiacType::Sidereal => ZodiacMode::Sidereal(self.ayanamsa.unwrap_or_default()),
+        }
+    }
+
+    /// House system the stored cusps were calculated with
+    pub fn house_system(&self) -> HouseSystem {
+        self.houses
+            .as_ref()
+            .and_then(|h| HouseSystem::from_name(&h.system))
+            .unwrap_or_default()
+    }
+
+    /// House system the chart was requested in (before any polar fallback)
+    pub fn requested_house_system(&self) -> HouseSystem {
+        self.house_system_fallback
+            .as_ref()
+            .map(|f| f.requested)
+            .unwrap_or_else(|| self.house_system())
+    }
+
     /// Get position for a planet
     pub fn get_planet_position(&self, planet: &Planet) -> Option<&ZodiacPosition> {
         self.planets.get(planet)
@@ -127,12 +480,219 @@ impl NatalChart {
         self.planet_positions.get(planet).map(|p| p.house)
     }
 
+    /// Calculate the aspects between the chart's planets (plus any selected additional points),
+    /// and from each of them to the Ascendant, Midheaven and Vertex
+    /// Angles and Arabic parts are treated as fixed points, so their motion comes from the planet alone.
+    pub fn calculate_aspects(
+        &self,
+        orbs: &OrbPolicy,
+        extra: &[AdditionalPoint],
+    ) -> Vec<NatalAspect> {
+        let bodies: Vec<(ChartPoint, f64, Option<f64>)> = self
+            .planet_points(extra)
+            .into_iter()
+            .map(|(point, longitude)| {
+                let speed = match point.planet() {
+                    Some(planet) => self.planet_positions.get(&planet).and_then(|p| p.speed),
+                    None => Some(0.0),
+                };
+                (point, longitude, speed)
+            })
+            .collect();
+        let angles: Vec<(ChartPoint, f64, Option<f64>)> = self
+            .angle_points(false)
+            .into_iter()
+            .map(|(point, longitude)| (point, longitude, Some(0.0)))
+            .collect();
+
+        let mut aspects = Vec::new();
+        let mut push_aspect =
+            |(point1, longitude1, speed1): (ChartPoint, f64, Option<f64>),
+             (point2, longitude2, speed2): (ChartPoint, f64, Option<f64>)| {
+                if point1.planet().is_none() && point2.planet().is_none()
+                    || linked_points(point1, point2)
+                {
+                    return;
+                }
+                if let Some((aspect, orb)) =
+                    orbs.find_point_aspect(point1, longitude1, point2, longitude2, false)
+                {
+                    let rate = speed1
+                        .zip(speed2)
+                        .map(|(s1, s2)| orb_rate(longitude1, s1, longitude2, s2, aspect));
+                    aspects.push(NatalAspect::new(
+                        point1.to_string(),
+                        point2.to_string(),
+                        aspect,
+                        orb,
+                        rate,
+                    ));
+                }
+            };
+
+        for (i, &body1) in bodies.iter().enumerate() {
+            for &body2 in &bodies[i + 1..] {
+                push_aspect(body1, body2);
+            }
+        }
+        for &body in &bodies {
+            for &angle in &angles {
+                push_aspect(body, angle);
+            }
+        }
+
+        aspects
+    }
+
+    /// Parallels and contra-parallels between the chart's planets (plus any selected additional
+    /// points that have a declination), if the policy looks for them
+    pub fn calculate_parallels(
+        &self,
+        orbs: &OrbPolicy,
+        extra: &[AdditionalPoint],
+    ) -> Vec<ParallelAspect> {
+        let declinations = self.planet_declinations(extra);
+
+        let mut parallels = Vec::new();
+        for (i, &(point1, declination1)) in declinations.iter().enumerate() {
+            for &(point2, declination2) in &declinations[i + 1..] {
+                if linked_points(point1, point2) {
+                    continue;
+                }
+                if let Some((aspect, orb)) = orbs.find_parallel(declination1, declination2) {
+                    parallels.push(ParallelAspect::new(
+                        point1.to_string(),
+                        point2.to_string(),
+                        aspect,
+                        orb,
+                    ));
+                }
+            }
+        }
+        parallels
+    }
+
+    /// Declinations of the planets and selected additional points, where recorded
+    /// (the Arabic parts have none)
+    pub fn planet_declinations(&self, extra: &[AdditionalPoint]) -> Vec<(ChartPoint, f64)> {
+        self.planet_points(extra)
+            .into_iter()
+            .filter_map(|(point, _)| Some((point, self.declination(point.planet()?)?)))
+            .collect()
+    }
+
+    /// Declination of a planet at birth
+    pub fn declination(&self, planet: Planet) -> Option<f64> {
+        self.planet_positions.get(&planet)?.declination
+    }
+
+    /// Whether a planet's declination at birth lies beyond the Sun's greatest declination
+    pub fn is_out_of_bounds(&self, planet: Planet) -> bool {
+        self.declination(planet)
+            .zip(self.obliquity)
+            .is_some_and(|(declination, obliquity)| declination.abs() > obliquity)
+    }
+
+    /// Every planet, then any selected additional points, with their longitudes
+    pub fn planet_points(&self, extra: &[AdditionalPoint]) -> Vec<(ChartPoint, f64)> {
+        let mut points: Vec<(ChartPoint, f64)> = Planet::all()
+            .iter()
+            .filter_map(|planet| {
+                Some((
+                    ChartPoint::Planet(*planet),
+                    self.planets.get(planet)?.longitude,
+                ))
+            })
+            .collect();
+
+        for additional in extra {
+            let point = additional.chart_point();
+            if points.iter().any(|(p, _)| *p == point) {
+                continue;
+            }
+            let position = match point {
+                ChartPoint::Planet(planet) => self.planets.get(&planet),
+                ChartPoint::PartOfFortune => self.part_of_fortune.as_ref(),
+                ChartPoint::PartOfSpirit => self.part_of_spirit.as_ref(),
+                _ => None,
+            };
+            if let Some(position) = position {
+                points.push((point, position.longitude));
+            }
+        }
+
+        points
+    }
+
+    /// Every planet and selected additional point, then the angles (and optionally the house
+    /// cusps), with their longitudes
+    pub fn natal_points(
+        &self,
+        extra: &[AdditionalPoint],
+        include_cusps: bool,
+    ) -> Vec<(ChartPoint, f64)> {
+        let mut points = self.planet_points(extra);
+        points.extend(self.angle_points(include_cusps));
+        points
+    }
+
+    /// Ascendant, Midheaven and Vertex with their longitudes, optionally followed by the
+    /// house cusps that don't coincide with an angle
+    pub fn angle_points(&self, include_cusps: bool) -> Vec<(ChartPoint, f64)> {
+        let mut points: Vec<(ChartPoint, f64)> = [
+            (ChartPoint::Ascendant, &self.ascendant),
+            (ChartPoint::Midheaven, &self.midheaven),
+            (ChartPoint::Vertex, &self.vertex),
+        ]
+        .into_iter()
+        .filter_map(|(point, position)| Some((point, position.as_ref()?.longitude)))
+        .collect();
+
+        if include_cusps {
+            let angles = points.clone();
+            for (house, longitude) in self.cusp_longitudes().into_iter().flatten().enumerate() {
+                let on_angle = angles.iter().any(|(_, a)| (a - longitude).abs() < 1e-6);
+                if !on_angle {
+                    points.push((ChartPoint::HouseCusp(house as u8 + 1), longitude));
+                }
+            }
+        }
+
+        points
+    }
+
+    /// Longitudes of the 12 house cusps, if houses were calculated
+    pub fn cusp_longitudes(&self) -> Option<[f64; 12]> {
+        let houses = self.houses.as_ref()?;
+        houses
+            .cusps
+            .iter()
+            .map(|c| c.longitude)
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()
+    }
+
     /// Get house cusp position by house number (1-12)
     pub fn get_house_cusp(&self, house_num: u8) -> Option<&ZodiacPosition> {
-        if house_num < 1 || house_num > 12 {
+        if !(1..=12).contains(&house_num) {
             return None;
         }
-        self.houses.as_ref().and_then(|h| h.cusps.get((house_num - 1) as usize))
+        self.houses
+            .as_ref()
+            .and_then(|h| h.cusps.get((house_num - 1) as usize))
+    }
+}
+
+/// Whether two points are tied together by definition (the nodes, or the two Liliths),
+/// so an aspect between them says nothing
+pub fn linked_points(point1: ChartPoint, point2: ChartPoint) -> bool {
+    match (point1.planet(), point2.planet()) {
+        (Some(planet1), Some(planet2)) => {
+            (planet1.is_lunar_node() && planet2.is_lunar_node())
+                || (planet1.is_lilith() && planet2.is_lilith())
+        }
+        _ => false,
     }
 }
 
@@ -141,6 +701,9 @@ impl NatalChart {
 pub struct StoreNatalChartResponse {
     pub success: bool,
     pub message: String,
+    /// Non-fatal issues, such as a house system fallback at polar latitudes
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
     pub natal_chart: NatalChartSummary,
 }
 
@@ -153,6 +716,12 @@ pub struct PlanetSummary {
     pub house: Option<u8>,
     /// Whether retrograde
     pub retrograde: bool,
+    /// Declination in degrees (negative = south)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declination: Option<f64>,
+    /// Declination beyond the Sun's greatest declination
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub out_of_bounds: bool,
 }
 
 /// House cusp summary
@@ -167,6 +736,11 @@ pub struct HouseSummary {
 /// Summary of natal chart positions for display
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct NatalChartSummary {
+    /// Zodiac the positions are expressed in (e.g., "Sidereal (Lahiri)")
+    pub zodiac: String,
+    /// Ayanamsa in degrees at birth (sidereal charts only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ayanamsa_value: Option<f64>,
     pub sun: PlanetSummary,
     pub moon: PlanetSummary,
     pub mercury: PlanetSummary,
@@ -178,8 +752,30 @@ pub struct NatalChartSummary {
     pub neptune: PlanetSummary,
     pub pluto: PlanetSummary,
     pub north_node: PlanetSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_node: Option<PlanetSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub south_node: Option<PlanetSummary>,
+    /// Black Moon Lilith (mean apogee)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_lilith: Option<PlanetSummary>,
+    /// Osculating (true) Lilith
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub true_lilith: Option<PlanetSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_of_fortune: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_of_spirit: Option<String>,
+    /// "day" or "night", which decides the formulas for the Arabic parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sect: Option<String>,
     pub ascendant: String,
     pub midheaven: String,
+    /// House system used for the cusps
+    pub house_system: String,
+    /// Set when the requested house system failed and a fallback was used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house_system_fallback: Option<HouseSystemFallback>,
     pub houses: Vec<HouseSummary>,
 }
 
@@ -202,9 +798,20 @@ impl From<&NatalChart> for NatalChartSummary {
                 position,
                 house,
                 retrograde,
+                declination: chart
+                    .declination(planet)
+                    .map(|d| (d * 100.0).round() / 100.0),
+                out_of_bounds: chart.is_out_of_bounds(planet),
             }
         };
 
+        let optional_summary = |planet: Planet| -> Option<PlanetSummary> {
+            chart
+                .planets
+                .contains_key(&planet)
+                .then(|| get_planet_summary(planet))
+        };
+
         let houses: Vec<HouseSummary> = chart
             .houses
             .as_ref()
@@ -221,6 +828,10 @@ impl From<&NatalChart> for NatalChartSummary {
             .unwrap_or_default();
 
         Self {
+            zodiac: chart.zodiac_mode().to_string(),
+            ayanamsa_value: chart
+                .ayanamsa_value
+                .map(|v| (v * 10000.0).round() / 10000.0),
             sun: get_planet_summary(Planet::Sun),
             moon: get_planet_summary(Planet::Moon),
             mercury: get_planet_summary(Planet::Mercury),
@@ -232,6 +843,21 @@ impl From<&NatalChart> for NatalChartSummary {
             neptune: get_planet_summary(Planet::Neptune),
             pluto: get_planet_summary(Planet::Pluto),
             north_node: get_planet_summary(Planet::NorthNode),
+            mean_node: optional_summary(Planet::MeanNode),
+            south_node: optional_summary(Planet::SouthNode),
+            mean_lilith: optional_summary(Planet::MeanLilith),
+            true_lilith: optional_summary(Planet::TrueLilith),
+            part_of_fortune: chart
+                .part_of_fortune
+                .as_ref()
+                .map(|p| p.format_degree_sign()),
+            part_of_spirit: chart
+                .part_of_spirit
+                .as_ref()
+                .map(|p| p.format_degree_sign()),
+            sect: chart
+                .is_day_chart
+                .map(|day| if day { "day" } else { "night" }.to_string()),
             ascendant: chart
                 .ascendant
                 .as_ref()
@@ -242,6 +868,12 @@ impl From<&NatalChart> for NatalChartSummary {
                 .as_ref()
                 .map(|p| p.format_degree_sign())
                 .unwrap_or_else(|| "Unknown".to_string()),
+            house_system: chart
+                .houses
+                .as_ref()
+                .map(|h| h.system.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            house_system_fallback: chart.house_system_fallback.clone(),
             houses,
         }
     }
@@ -0,0 +1,496 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{
+    find_declination_aspect, AspectFamily, AspectType, ChartPoint, DeclinationAspect, Planet,
+};
+
+/// How the orb of an aspect is determined
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OrbMethod {
+    /// Each aspect has its own orb (e.g., 8° conjunction, 6° sextile)
+    #[default]
+    PerAspect,
+    /// Each planet has its own orb; the larger of the two applies
+    PerPlanet,
+    /// Traditional moieties: half of each planet's orb, added together
+    Moiety,
+}
+
+/// Orb settings for one kind of comparison (natal, transit or synastry)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct OrbPolicy {
+    /// Aspects to look for (default: the five major aspects, plus minor ones when requested)
+    #[schemars(
+        description = "Aspects to look for, e.g. ['conjunction', 'square', 'opposition']. Default: major aspects (plus every minor and harmonic aspect when include_minor_aspects is set)"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspects: Option<Vec<AspectType>>,
+
+    /// Harmonic families to look for when no explicit aspect list is given
+    #[schemars(
+        description = "Aspect families to look for when 'aspects' is not given: 'major', 'minor', 'quintile', 'septile', 'novile'"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub families: Option<Vec<AspectFamily>>,
+
+    /// How orbs are determined
+    #[schemars(
+        description = "Orb method: 'per_aspect' (default), 'per_planet' (larger planet orb applies) or 'moiety' (half of each planet's orb, added)"
+    )]
+    #[serde(default)]
+    pub method: OrbMethod,
+
+    /// Orb overrides per aspect, in degrees
+    #[schemars(
+        description = "Orb per aspect in degrees, e.g. {'conjunction': 10, 'sextile': 4}. Used by 'per_aspect' and always for minor aspects"
+    )]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub aspect_orbs: HashMap<AspectType, f64>,
+
+    /// Orb overrides per planet, in degrees
+    #[schemars(
+        description = "Orb per planet in degrees for 'per_planet' and 'moiety', e.g. {'sun': 15, 'moon': 12}. Defaults follow Lilly's orbs"
+    )]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub planet_orbs: HashMap<Planet, f64>,
+
+    /// Extra orb when the Sun or Moon is involved, in degrees
+    #[schemars(description = "Extra degrees of orb when the Sun or Moon is involved (default: 0)")]
+    #[serde(default)]
+    pub luminary_bonus: f64,
+
+    /// Whether to look for parallels and contra-parallels of declination
+    #[schemars(
+        description = "Also look for parallels and contra-parallels of declination (default: false)"
+    )]
+    #[serde(default)]
+    pub parallels: bool,
+
+    /// Orb for parallels and contra-parallels, in degrees of declination
+    #[schemars(
+        description = "Orb for parallels and contra-parallels in degrees of declination (default: 1)"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel_orb: Option<f64>,
+}
+
+impl OrbPolicy {
+    /// Default full orb of a planet (after William Lilly; modern planets and the nodes 5°, Lilith 3°)
+    pub fn default_planet_orb(planet: Planet) -> f64 {
+        match planet {
+            Planet::Sun => 15.0,
+            Planet::Moon => 12.0,
+            Planet::Mercury | Planet::Venus => 7.0,
+            Planet::Mars => 7.5,
+            Planet::Jupiter | Planet::Saturn => 9.0,
+            Planet::Uranus
+            | Planet::Neptune
+            | Planet::Pluto
+            | Planet::NorthNode
+            | Planet::MeanNode
+            | Planet::SouthNode => 5.0,
+            Planet::MeanLilith | Planet::TrueLilith => 3.0,
+        }
+    }
+
+    /// Check that every orb is a usable number of degrees
+    pub fn validate(&self) -> Result<(), String> {
+        let orbs = self
+            .aspect_orbs
+            .values()
+            .chain(self.planet_orbs.values())
+            .chain(std::iter::once(&self.luminary_bonus))
+            .chain(self.parallel_orb.iter());
+        for orb in orbs {
+            if !orb.is_finite() || *orb < 0.0 || *orb > 30.0 {
+                return Err(format!(
+                    "Invalid orb {}: orbs must be between 0 and 30 degrees",
+                    orb
+                ));
+            }
+        }
+        if self.aspects.as_ref().is_some_and(|a| a.is_empty())
+            || self.families.as_ref().is_some_and(|f| f.is_empty())
+        {
+            return Err("At least one aspect must be enabled".to_string());
+        }
+        Ok(())
+    }
+
+    /// Aspects this policy looks for
+    pub fn enabled_aspects(&self, include_minor: bool) -> Vec<AspectType> {
+        match (&self.aspects, &self.families) {
+            (Some(aspects), _) => aspects.clone(),
+            (None, Some(families)) => AspectType::aspects_in_families(families),
+            (None, None) if include_minor => AspectType::all_aspects().to_vec(),
+            (None, None) => AspectType::major_aspects().to_vec(),
+        }
+    }
+
+    fn aspect_orb(&self, aspect: AspectType) -> f64 {
+        self.aspect_orbs
+            .get(&aspect)
+            .copied()
+            .unwrap_or_else(|| aspect.default_orb())
+    }
+
+    fn planet_orb(&self, planet: Planet) -> f64 {
+        self.planet_orbs
+            .get(&planet)
+            .copied()
+            .unwrap_or_else(|| Self::default_planet_orb(planet))
+    }
+
+    /// Allowed orb for an aspect between two planets
+    /// Planet-based methods apply to major aspects; minor aspects keep their per-aspect orb.
+    pub fn orb(&self, aspect: AspectType, planet1: Planet, planet2: Planet) -> f64 {
+        let base = match self.method {
+            OrbMethod::PerPlanet if aspect.is_major() => {
+                self.planet_orb(planet1).max(self.planet_orb(planet2))
+            }
+            OrbMethod::Moiety if aspect.is_major() => {
+                (self.planet_orb(planet1) + self.planet_orb(planet2)) / 2.0
+            }
+            _ => self.aspect_orb(aspect),
+        };
+
+        let luminary = [planet1, planet2]
+            .iter()
+            .any(|p| matches!(p, Planet::Sun | Planet::Moon));
+        if luminary {
+            base + self.luminary_bonus
+        } else {
+            base
+        }
+    }
+
+    /// Allowed orb for an aspect between any two chart points
+    /// Angles and cusps have no planetary orb, so the per-aspect orb applies to them.
+    pub fn point_orb(&self, aspect: AspectType, point1: ChartPoint, point2: ChartPoint) -> f64 {
+        match (point1.planet(), point2.planet()) {
+            (Some(planet1), Some(planet2)) => self.orb(aspect, planet1, planet2),
+            (planet1, planet2) => {
+                let luminary = planet1
+                    .or(planet2)
+                    .is_some_and(|p| matches!(p, Planet::Sun | Planet::Moon));
+                if luminary {
+                    self.aspect_orb(aspect) + self.luminary_bonus
+                } else {
+                    self.aspect_orb(aspect)
+                }
+            }
+        }
+    }
+
+    /// The closest enabled aspect between two planets that is within orb
+    pub fn find_aspect(
+        &self,
+        planet1: Planet,
+        longitude1: f64,
+        planet2: Planet,
+        longitude2: f64,
+        include_minor: bool,
+    ) -> Option<(AspectType, f64)> {
+        closest_aspect(
+            longitude1,
+            longitude2,
+            &self.enabled_aspects(include_minor),
+            |aspect| self.orb(aspect, planet1, planet2),
+        )
+    }
+
+    /// The closest enabled aspect between any two chart points that is within orb
+    pub fn find_point_aspect(
+        &self,
+        point1: ChartPoint,
+        longitude1: f64,
+        point2: ChartPoint,
+        longitude2: f64,
+        include_minor: bool,
+    ) -> Option<(AspectType, f64)> {
+        closest_aspect(
+            longitude1,
+            longitude2,
+            &self.enabled_aspects(include_minor),
+            |aspect| self.point_orb(aspect, point1, point2),
+        )
+    }
+
+    /// The parallel or contra-parallel between two declinations, if parallels are enabled
+    pub fn find_parallel(
+        &self,
+        declination1: f64,
+        declination2: f64,
+    ) -> Option<(DeclinationAspect, f64)> {
+        find_declination_aspect(declination1, declination2, self.declination_orb()?)
+    }
+
+    /// Orb for parallels and contra-parallels, if the policy looks for them
+    pub fn declination_orb(&self) -> Option<f64> {
+        self.parallels.then(|| {
+            self.parallel_orb
+                .unwrap_or_else(DeclinationAspect::default_orb)
+        })
+    }
+}
+
+/// Server-wide default orb tables for each kind of comparison
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct OrbSettings {
+    /// Aspects within a single natal chart
+    #[serde(default)]
+    pub natal: OrbPolicy,
+    /// Transiting planets to natal planets
+    #[serde(default)]
+    pub transit: OrbPolicy,
+    /// One person's planets to another's
+    #[serde(default)]
+    pub synastry: OrbPolicy,
+}
+
+/// Find the aspect whose exact angle is closest to the separation, among those within orb
+pub fn closest_aspect(
+    longitude1: f64,
+    longitude2: f64,
+    aspects: &[AspectType],
+    orb: impl Fn(AspectType) -> f64,
+) -> Option<(AspectType, f64)> {
+    // Calculate the shortest angular distance
+    let diff = (longitude1 - longitude2).abs();
+    let angular_distance = if diff > 180.0 { 360.0 - diff } else { diff };
+
+    aspects
+        .iter()
+        .map(|aspect| (*aspect, (angular_distance - aspect.angle()).abs()))
+        .filter(|(aspect, distance)| *distance <= orb(*aspect))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_per_aspect_orbs() {
+        let policy = OrbPolicy {
+            aspect_orbs: [(AspectType::Square, 4.0)].into_iter().collect(),
+            ..OrbPolicy::default()
+        };
+        assert_eq!(
+            policy.orb(AspectType::Square, Planet::Mars, Planet::Saturn),
+            4.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Trine, Planet::Mars, Planet::Saturn),
+            8.0
+        );
+        assert_eq!(
+            policy.find_aspect(Planet::Mars, 0.0, Planet::Saturn, 95.0, false),
+            None
+        );
+        assert_eq!(
+            policy.find_aspect(Planet::Mars, 0.0, Planet::Saturn, 93.0, false),
+            Some((AspectType::Square, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_per_planet_orbs() {
+        let policy = OrbPolicy {
+            method: OrbMethod::PerPlanet,
+            planet_orbs: [(Planet::Mars, 10.0)].into_iter().collect(),
+            ..OrbPolicy::default()
+        };
+        // The larger of the two planets' orbs applies
+        assert_eq!(
+            policy.orb(AspectType::Square, Planet::Mars, Planet::Pluto),
+            10.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Square, Planet::Venus, Planet::Pluto),
+            7.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Trine, Planet::Sun, Planet::Pluto),
+            15.0
+        );
+        // Minor aspects keep their per-aspect orb
+        assert_eq!(
+            policy.orb(AspectType::Quincunx, Planet::Mars, Planet::Pluto),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_moiety_orbs() {
+        let policy = OrbPolicy {
+            method: OrbMethod::Moiety,
+            ..OrbPolicy::default()
+        };
+        // Half of the Sun's 15° plus half of Saturn's 9°
+        assert_eq!(
+            policy.orb(AspectType::Opposition, Planet::Sun, Planet::Saturn),
+            12.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Sextile, Planet::Mercury, Planet::Neptune),
+            6.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::SemiSquare, Planet::Sun, Planet::Saturn),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_luminary_bonus() {
+        let policy = OrbPolicy {
+            luminary_bonus: 2.0,
+            ..OrbPolicy::default()
+        };
+        assert_eq!(
+            policy.orb(AspectType::Conjunction, Planet::Moon, Planet::Mars),
+            10.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Conjunction, Planet::Sun, Planet::Moon),
+            10.0
+        );
+        assert_eq!(
+            policy.orb(AspectType::Conjunction, Planet::Venus, Planet::Mars),
+            8.0
+        );
+
+        let moiety = OrbPolicy {
+            method: OrbMethod::Moiety,
+            ..policy
+        };
+        assert_eq!(
+            moiety.orb(AspectType::Trine, Planet::Moon, Planet::Jupiter),
+            12.5
+        );
+    }
+
+    #[test]
+    fn test_enabled_aspects() {
+        let default = OrbPolicy::default();
+        assert_eq!(
+            default.enabled_aspects(false),
+            AspectType::major_aspects().to_vec()
+        );
+        assert_eq!(
+            default.enabled_aspects(true),
+            AspectType::all_aspects().to_vec()
+        );
+
+        let families = OrbPolicy {
+            families: Some(vec![AspectFamily::Septile]),
+            ..OrbPolicy::default()
+        };
+        assert_eq!(
+            families.enabled_aspects(true),
+            vec![
+                AspectType::Septile,
+                AspectType::Biseptile,
+                AspectType::Triseptile
+            ]
+        );
+
+        // An explicit list wins over families and include_minor
+        let explicit = OrbPolicy {
+            aspects: Some(vec![AspectType::Square]),
+            ..families
+        };
+        assert_eq!(explicit.enabled_aspects(true), vec![AspectType::Square]);
+        assert_eq!(
+            explicit.find_aspect(Planet::Sun, 0.0, Planet::Moon, 120.0, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(OrbPolicy::default().validate().is_ok());
+
+        let invalid = [
+            OrbPolicy {
+                aspect_orbs: [(AspectType::Trine, -1.0)].into_iter().collect(),
+                ..OrbPolicy::default()
+            },
+            OrbPolicy {
+                planet_orbs: [(Planet::Sun, f64::NAN)].into_iter().collect(),
+                ..OrbPolicy::default()
+            },
+            OrbPolicy {
+                luminary_bonus: 45.0,
+                ..OrbPolicy::default()
+            },
+            OrbPolicy {
+                aspects: Some(Vec::new()),
+                ..OrbPolicy::default()
+            },
+            OrbPolicy {
+                families: Some(Vec::new()),
+                ..OrbPolicy::default()
+            },
+        ];
+        for policy in invalid {
+            assert!(policy.validate().is_err(), "{:?}", policy);
+        }
+    }
+
+    #[test]
+    fn test_point_orb() {
+        let policy = OrbPolicy {
+            method: OrbMethod::PerPlanet,
+            luminary_bonus: 1.0,
+            ..OrbPolicy::default()
+        };
+        let saturn = ChartPoint::Planet(Planet::Saturn);
+
+        // Two planets use the policy's method
+        assert_eq!(
+            policy.point_orb(AspectType::Square, saturn, ChartPoint::Planet(Planet::Mars)),
+            9.0
+        );
+        // Angles, cusps and parts have no planetary orb, so the per-aspect orb applies
+        assert_eq!(
+            policy.point_orb(AspectType::Square, saturn, ChartPoint::Ascendant),
+            7.0
+        );
+        assert_eq!(
+            policy.point_orb(AspectType::Square, ChartPoint::HouseCusp(4), saturn),
+            7.0
+        );
+        assert_eq!(
+            policy.point_orb(AspectType::Square, ChartPoint::PartOfFortune, saturn),
+            7.0
+        );
+        // The luminary bonus still applies when the Sun or Moon is one side
+        assert_eq!(
+            policy.point_orb(
+                AspectType::Trine,
+                ChartPoint::Planet(Planet::Moon),
+                ChartPoint::Midheaven
+            ),
+            9.0
+        );
+        assert_eq!(
+            policy.point_orb(AspectType::Trine, ChartPoint::Vertex, ChartPoint::Midheaven),
+            8.0
+        );
+
+        assert_eq!(
+            policy.find_point_aspect(saturn, 100.0, ChartPoint::Ascendant, 12.0, false),
+            Some((AspectType::Square, 2.0))
+        );
+        assert_eq!(
+            policy.find_point_aspect(saturn, 100.0, ChartPoint::Ascendant, 2.0, false),
+            None
+        );
+    }
+}
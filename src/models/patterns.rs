@@ -0,0 +1,664 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use super::{closest_aspect, AspectType, Element, Modality, OrbPolicy, Planet, ZodiacSign};
+
+/// Aspects that make up the configurations below
+const PATTERN_ASPECTS: &[AspectType] = &[
+    AspectType::Conjunction,
+    AspectType::Sextile,
+    AspectType::Square,
+    AspectType::Trine,
+    AspectType::Quincunx,
+    AspectType::Opposition,
+];
+
+/// Minimum number of planets for a stellium
+const STELLIUM_SIZE: usize = 3;
+
+/// Multi-planet aspect configurations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AspectPatternType {
+    GrandTrine,
+    TSquare,
+    GrandCross,
+    Yod,
+    Kite,
+    MysticRectangle,
+    GrandSextile,
+    StelliumSign,
+    StelliumHouse,
+}
+
+impl fmt::Display for AspectPatternType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AspectPatternType::GrandTrine => "Grand Trine",
+            AspectPatternType::TSquare => "T-Square",
+            AspectPatternType::GrandCross => "Grand Cross",
+            AspectPatternType::Yod => "Yod",
+            AspectPatternType::Kite => "Kite",
+            AspectPatternType::MysticRectangle => "Mystic Rectangle",
+            AspectPatternType::GrandSextile => "Grand Sextile",
+            AspectPatternType::StelliumSign => "Stellium (sign)",
+            AspectPatternType::StelliumHouse => "Stellium (house)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Which positions to search for patterns
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternSource {
+    /// The natal chart's planets
+    #[default]
+    Natal,
+    /// The sky at a given moment (in the natal chart's houses when a chart is given)
+    Transit,
+    /// Midpoint composite of two charts
+    Composite,
+    /// Both charts overlaid; only patterns linking the two people count
+    Synastry,
+}
+
+impl fmt::Display for PatternSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PatternSource::Natal => "natal",
+            PatternSource::Transit => "transit",
+            PatternSource::Composite => "composite",
+            PatternSource::Synastry => "synastry",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A body taking part in pattern detection
+#[derive(Debug, Clone)]
+pub struct PatternBody {
+    /// Label used in the output (e.g., "Sun" or "Jane: Sun")
+    pub name: String,
+    pub planet: Planet,
+    pub longitude: f64,
+    /// House the body falls in, when houses are known
+    pub house: Option<u8>,
+    /// Chart the body belongs to in an overlay; patterns must then span more than one owner
+    pub owner: Option<String>,
+}
+
+/// A detected aspect configuration
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct AspectPattern {
+    pub pattern: AspectPatternType,
+    /// Participating bodies
+    pub bodies: Vec<String>,
+    /// Focal body (T-square and yod apex, the kite's head)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apex: Option<String>,
+    /// Element shared by the pattern (grand trine, kite, sign stellium)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub element: Option<Element>,
+    /// Modality shared by the pattern (T-square, grand cross, sign stellium)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modality: Option<Modality>,
+    /// Sign of a sign stellium
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign: Option<ZodiacSign>,
+    /// House of a house stellium
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house: Option<u8>,
+    /// Tightest orb among the aspects forming the pattern, in degrees
+    pub tightest_orb: f64,
+}
+
+/// Aspect lookup between every pair of bodies
+struct AspectMatrix {
+    aspects: Vec<Vec<Option<(AspectType, f64)>>>,
+}
+
+impl AspectMatrix {
+    fn new(bodies: &[PatternBody], orbs: &OrbPolicy) -> Self {
+        let aspects = bodies
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                bodies
+                    .iter()
+                    .enumerate()
+                    .map(|(j, b)| {
+                        if i == j {
+                            return None;
+                        }
+                        closest_aspect(a.longitude, b.longitude, PATTERN_ASPECTS, |aspect| {
+                            orbs.orb(aspect, a.planet, b.planet)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { aspects }
+    }
+
+    fn is(&self, i: usize, j: usize, aspect: AspectType) -> bool {
+        matches!(self.aspects[i][j], Some((a, _)) if a == aspect)
+    }
+
+    fn orb(&self, i: usize, j: usize) -> f64 {
+        self.aspects[i][j].map(|(_, orb)| orb).unwrap_or(f64::MAX)
+    }
+
+    /// Tightest orb among the given pairs
+    fn tightest(&self, pairs: &[(usize, usize)]) -> f64 {
+        pairs
+            .iter()
+            .map(|&(i, j)| self.orb(i, j))
+            .fold(f64::MAX, f64::min)
+    }
+
+    /// Count each aspect type among all pairs of the given bodies
+    fn count(&self, indices: &[usize], aspect: AspectType) -> usize {
+        pairs_of(indices)
+            .into_iter()
+            .filter(|&(i, j)| self.is(i, j, aspect))
+            .count()
+    }
+}
+
+/// Every unordered pair of the given indices
+fn pairs_of(indices: &[usize]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (n, &i) in indices.iter().enumerate() {
+        for &j in &indices[n + 1..] {
+            pairs.push((i, j));
+        }
+    }
+    pairs
+}
+
+/// Every combination of `size` indices below `n`, in increasing order
+fn combinations(n: usize, size: usize) -> Vec<Vec<usize>> {
+    fn extend(
+        start: usize,
+        n: usize,
+        size: usize,
+        current: &mut Vec<usize>,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if current.len() == size {
+            out.push(current.clone());
+            return;
+        }
+        for i in start..n {
+            current.push(i);
+            extend(i + 1, n, size, current, out);
+            current.pop();
+        }
+    }
+
+    let mut out = Vec::new();
+    extend(0, n, size, &mut Vec::new(), &mut out);
+    out
+}
+
+/// The value every sign shares, if they all agree
+fn shared<T: PartialEq>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut values = values.into_iter();
+    let first = values.next()?;
+    values.all(|v| v == first).then_some(first)
+}
+
+/// Find grand trines, T-squares, grand crosses, yods, kites, mystic rectangles, grand sextiles
+/// and stellia (by sign and by house) among the given bodies
+///
+/// When bodies carry owners (a synastry overlay), only patterns linking more than one chart are kept.
+pub fn find_aspect_patterns(bodies: &[PatternBody], orbs: &OrbPolicy) -> Vec<AspectPattern> {
+    let matrix = AspectMatrix::new(bodies, orbs);
+    let n = bodies.len();
+    let sign = |i: usize| ZodiacSign::from_longitude(bodies[i].longitude);
+    let pattern =
+        |pattern: AspectPatternType, indices: &[usize], tightest_orb: f64| AspectPattern {
+            pattern,
+            bodies: indices.iter().map(|&i| bodies[i].name.clone()).collect(),
+            apex: None,
+            element: None,
+            modality: None,
+            sign: None,
+            house: None,
+            tightest_orb: (tightest_orb * 100.0).round() / 100.0,
+        };
+
+    let mut patterns = Vec::new();
+    let mut grand_trines = Vec::new();
+
+    for trio in combinations(n, 3) {
+        let (a, b, c) = (trio[0], trio[1], trio[2]);
+        let pairs = pairs_of(&trio);
+
+        if matrix.count(&trio, AspectType::Trine) == 3 {
+            grand_trines.push(trio.clone());
+            patterns.push(AspectPattern {
+                element: shared(trio.iter().map(|&i| sign(i).element())),
+                ..pattern(
+                    AspectPatternType::GrandTrine,
+                    &trio,
+                    matrix.tightest(&pairs),
+                )
+            });
+        }
+
+        // T-square and yod: a base pair with an apex aspecting both ends
+        for (apex, base1, base2) in [(a, b, c), (b, a, c), (c, a, b)] {
+            let (apex_aspect, kind) = if matrix.is(base1, base2, AspectType::Opposition) {
+                (AspectType::Square, AspectPatternType::TSquare)
+            } else if matrix.is(base1, base2, AspectType::Sextile) {
+                (AspectType::Quincunx, AspectPatternType::Yod)
+            } else {
+                continue;
+            };
+            if !matrix.is(apex, base1, apex_aspect) || !matrix.is(apex, base2, apex_aspect) {
+                continue;
+            }
+
+            let modality = if kind == AspectPatternType::TSquare {
+                shared(trio.iter().map(|&i| sign(i).modality()))
+            } else {
+                None
+            };
+            patterns.push(AspectPattern {
+                apex: Some(bodies[apex].name.clone()),
+                modality,
+                ..pattern(kind, &[base1, base2, apex], matrix.tightest(&pairs))
+            });
+        }
+    }
+
+    for quad in combinations(n, 4) {
+        let pairs = pairs_of(&quad);
+        let oppositions = matrix.count(&quad, AspectType::Opposition);
+
+        if oppositions == 2 && matrix.count(&quad, AspectType::Square) == 4 {
+            patterns.push(AspectPattern {
+                modality: shared(quad.iter().map(|&i| sign(i).modality())),
+                ..pattern(
+                    AspectPatternType::GrandCross,
+                    &quad,
+                    matrix.tightest(&pairs),
+                )
+            });
+        }
+
+        if oppositions == 2
+            && matrix.count(&quad, AspectType::Trine) == 2
+            && matrix.count(&quad, AspectType::Sextile) == 2
+        {
+            patterns.push(pattern(
+                AspectPatternType::MysticRectangle,
+                &quad,
+                matrix.tightest(&pairs),
+            ));
+        }
+    }
+
+    // Kite: a grand trine with a fourth body opposing one corner and sextile the other two
+    for trio in &grand_trines {
+        for tail in (0..n).filter(|i| !trio.contains(i)) {
+            for (head, wing1, wing2) in [
+                (trio[0], trio[1], trio[2]),
+                (trio[1], trio[0], trio[2]),
+                (trio[2], trio[0], trio[1]),
+            ] {
+                if matrix.is(tail, head, AspectType::Opposition)
+                    && matrix.is(tail, wing1, AspectType::Sextile)
+                    && matrix.is(tail, wing2, AspectType::Sextile)
+                {
+                    let indices = [head, wing1, wing2, tail];
+                    patterns.push(AspectPattern {
+                        apex: Some(bodies[head].name.clone()),
+                        element: shared(trio.iter().map(|&i| sign(i).element())),
+                        ..pattern(
+                            AspectPatternType::Kite,
+                            &indices,
+                            matrix.tightest(&pairs_of(&indices)),
+                        )
+                    });
+                }
+            }
+        }
+    }
+
+    // Grand sextile: two disjoint grand trines whose corners oppose each other
+    for (n1, first) in grand_trines.iter().enumerate() {
+        for second in &grand_trines[n1 + 1..] {
+            if first.iter().any(|i| second.contains(i)) {
+                continue;
+            }
+            let opposed = first.iter().all(|&i| {
+                second
+                    .iter()
+                    .any(|&j| matrix.is(i, j, AspectType::Opposition))
+            });
+            if opposed {
+                let indices: Vec<usize> = first.iter().chain(second).copied().collect();
+                patterns.push(pattern(
+                    AspectPatternType::GrandSextile,
+                    &indices,
+                    matrix.tightest(&pairs_of(&indices)),
+                ));
+            }
+        }
+    }
+
+    // Stellia leave out the lunar node, which isn't a planet
+    let stellium_bodies: Vec<usize> = (0..n)
+        .filter(|&i| !bodies[i].planet.is_lunar_node())
+        .collect();
+    let closest_pair = |indices: &[usize]| {
+        pairs_of(indices)
+            .into_iter()
+            .map(|(i, j)| {
+                let diff = (bodies[i].longitude - bodies[j].longitude).abs();
+                if diff > 180.0 {
+                    360.0 - diff
+                } else {
+                    diff
+                }
+            })
+            .fold(f64::MAX, f64::min)
+    };
+
+    let mut by_sign: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let mut by_house: BTreeMap<u8, Vec<usize>> = BTreeMap::new();
+    for &i in &stellium_bodies {
+        by_sign.entry(sign(i).index()).or_default().push(i);
+        if let Some(house) = bodies[i].house {
+            by_house.entry(house).or_default().push(i);
+        }
+    }
+
+    for (sign_index, indices) in by_sign
+        .into_iter()
+        .filter(|(_, v)| v.len() >= STELLIUM_SIZE)
+    {
+        let sign = ZodiacSign::from_index(sign_index);
+        patterns.push(AspectPattern {
+            element: Some(sign.element()),
+            modality: Some(sign.modality()),
+            sign: Some(sign),
+            ..pattern(
+                AspectPatternType::StelliumSign,
+                &indices,
+                closest_pair(&indices),
+            )
+        });
+    }
+    for (house, indices) in by_house
+        .into_iter()
+        .filter(|(_, v)| v.len() >= STELLIUM_SIZE)
+    {
+        patterns.push(AspectPattern {
+            house: Some(house),
+            ..pattern(
+                AspectPatternType::StelliumHouse,
+                &indices,
+                closest_pair(&indices),
+            )
+        });
+    }
+
+    remove_contained(&mut patterns);
+
+    // In an overlay, keep only patterns that link the charts
+    if bodies.iter().any(|b| b.owner.is_some()) {
+        patterns.retain(|p| {
+            let owners: HashSet<Option<&String>> = p
+                .bodies
+                .iter()
+                .filter_map(|name| bodies.iter().find(|b| &b.name == name))
+                .map(|b| b.owner.as_ref())
+                .collect();
+            owners.len() > 1
+        });
+    }
+
+    patterns
+}
+
+/// Drop T-squares inside a grand cross, and grand trines, kites and mystic rectangles inside
+/// a grand sextile, which are implied by the larger pattern
+fn remove_contained(patterns: &mut Vec<AspectPattern>) {
+    let containers: Vec<(AspectPatternType, HashSet<String>)> = patterns
+        .iter()
+        .filter(|p| {
+            matches!(
+                p.pattern,
+                AspectPatternType::GrandCross | AspectPatternType::GrandSextile
+            )
+        })
+        .map(|p| (p.pattern, p.bodies.iter().cloned().collect()))
+        .collect();
+
+    patterns.retain(|p| {
+        let container = match p.pattern {
+            AspectPatternType::TSquare => AspectPatternType::GrandCross,
+            AspectPatternType::GrandTrine
+            | AspectPatternType::Kite
+            | AspectPatternType::MysticRectangle => AspectPatternType::GrandSextile,
+            _ => return true,
+        };
+        !containers
+            .iter()
+            .any(|(kind, bodies)| *kind == container && p.bodies.iter().all(|b| bodies.contains(b)))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(planet: Planet, longitude: f64) -> PatternBody {
+        PatternBody {
+            name: planet.to_string(),
+            planet,
+            longitude,
+            house: None,
+            owner: None,
+        }
+    }
+
+    fn patterns(bodies: &[PatternBody]) -> Vec<AspectPattern> {
+        find_aspect_patterns(bodies, &OrbPolicy::default())
+    }
+
+    fn kinds(patterns: &[AspectPattern]) -> Vec<AspectPatternType> {
+        patterns.iter().map(|p| p.pattern).collect()
+    }
+
+    #[test]
+    fn test_grand_trine() {
+        let found = patterns(&[
+            body(Planet::Sun, 2.0),
+            body(Planet::Moon, 121.0),
+            body(Planet::Mars, 243.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::GrandTrine]);
+        assert_eq!(found[0].element, Some(Element::Fire));
+        assert_eq!(found[0].tightest_orb, 1.0);
+    }
+
+    #[test]
+    fn test_t_square() {
+        let found = patterns(&[
+            body(Planet::Sun, 0.0),
+            body(Planet::Moon, 180.0),
+            body(Planet::Mars, 92.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::TSquare]);
+        assert_eq!(found[0].apex.as_deref(), Some("Mars"));
+        assert_eq!(found[0].modality, Some(Modality::Cardinal));
+    }
+
+    #[test]
+    fn test_grand_cross_contains_its_t_squares() {
+        let found = patterns(&[
+            body(Planet::Sun, 0.0),
+            body(Planet::Moon, 90.0),
+            body(Planet::Mars, 180.0),
+            body(Planet::Saturn, 270.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::GrandCross]);
+        assert_eq!(found[0].modality, Some(Modality::Cardinal));
+    }
+
+    #[test]
+    fn test_yod() {
+        let found = patterns(&[
+            body(Planet::Sun, 0.0),
+            body(Planet::Moon, 60.0),
+            body(Planet::Pluto, 210.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::Yod]);
+        assert_eq!(found[0].apex.as_deref(), Some("Pluto"));
+        assert_eq!(found[0].bodies, vec!["Sun", "Moon", "Pluto"]);
+    }
+
+    #[test]
+    fn test_kite() {
+        let found = patterns(&[
+            body(Planet::Sun, 0.0),
+            body(Planet::Moon, 120.0),
+            body(Planet::Mars, 240.0),
+            body(Planet::Saturn, 180.0),
+        ]);
+        assert_eq!(
+            kinds(&found),
+            vec![AspectPatternType::GrandTrine, AspectPatternType::Kite]
+        );
+        let kite = &found[1];
+        assert_eq!(kite.apex.as_deref(), Some("Sun"));
+        assert_eq!(kite.bodies, vec!["Sun", "Moon", "Mars", "Saturn"]);
+        assert_eq!(kite.element, Some(Element::Fire));
+    }
+
+    #[test]
+    fn test_mystic_rectangle() {
+        let found = patterns(&[
+            body(Planet::Sun, 0.0),
+            body(Planet::Moon, 60.0),
+            body(Planet::Mars, 180.0),
+            body(Planet::Saturn, 240.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::MysticRectangle]);
+    }
+
+    #[test]
+    fn test_grand_sextile_contains_smaller_patterns() {
+        let planets = [
+            Planet::Sun,
+            Planet::Moon,
+            Planet::Mercury,
+            Planet::Venus,
+            Planet::Mars,
+            Planet::Jupiter,
+        ];
+        let bodies: Vec<PatternBody> = planets
+            .iter()
+            .enumerate()
+            .map(|(i, planet)| body(*planet, i as f64 * 60.0))
+            .collect();
+        let found = patterns(&bodies);
+        assert_eq!(kinds(&found), vec![AspectPatternType::GrandSextile]);
+        assert_eq!(found[0].bodies.len(), 6);
+    }
+
+    #[test]
+    fn test_remove_contained() {
+        let pattern = |kind: AspectPatternType, bodies: &[&str]| AspectPattern {
+            pattern: kind,
+            bodies: bodies.iter().map(|b| b.to_string()).collect(),
+            apex: None,
+            element: None,
+            modality: None,
+            sign: None,
+            house: None,
+            tightest_orb: 0.0,
+        };
+        let mut found = vec![
+            pattern(
+                AspectPatternType::GrandCross,
+                &["Sun", "Moon", "Mars", "Saturn"],
+            ),
+            pattern(AspectPatternType::TSquare, &["Sun", "Mars", "Moon"]),
+            // Shares only two bodies with the grand cross
+            pattern(AspectPatternType::TSquare, &["Sun", "Mars", "Venus"]),
+            // A grand trine is only implied by a grand sextile, not a grand cross
+            pattern(AspectPatternType::GrandTrine, &["Sun", "Moon", "Mars"]),
+        ];
+        remove_contained(&mut found);
+        assert_eq!(
+            kinds(&found),
+            vec![
+                AspectPatternType::GrandCross,
+                AspectPatternType::TSquare,
+                AspectPatternType::GrandTrine
+            ]
+        );
+        assert_eq!(found[1].bodies, vec!["Sun", "Mars", "Venus"]);
+    }
+
+    #[test]
+    fn test_stellium() {
+        let in_first_house = |planet: Planet, longitude: f64| PatternBody {
+            house: Some(1),
+            ..body(planet, longitude)
+        };
+        let found = patterns(&[
+            in_first_house(Planet::Sun, 10.0),
+            in_first_house(Planet::Mercury, 14.0),
+            in_first_house(Planet::Venus, 20.0),
+            // The node doesn't count towards a stellium
+            in_first_house(Planet::NorthNode, 12.0),
+            in_first_house(Planet::Mars, 35.0),
+        ]);
+        assert_eq!(
+            kinds(&found),
+            vec![
+                AspectPatternType::StelliumSign,
+                AspectPatternType::StelliumHouse
+            ]
+        );
+
+        let sign = &found[0];
+        assert_eq!(sign.sign, Some(ZodiacSign::Aries));
+        assert_eq!(sign.bodies, vec!["Sun", "Mercury", "Venus"]);
+        assert_eq!(sign.tightest_orb, 4.0);
+        let house = &found[1];
+        assert_eq!(house.house, Some(1));
+        assert_eq!(house.bodies, vec!["Sun", "Mercury", "Venus", "Mars"]);
+    }
+
+    #[test]
+    fn test_synastry_patterns_must_link_both_charts() {
+        let owned = |owner: &str, planet: Planet, longitude: f64| PatternBody {
+            name: format!("{}: {}", owner, planet),
+            owner: Some(owner.to_string()),
+            ..body(planet, longitude)
+        };
+        // Jane's grand trine is her own; Tom's Saturn turns it into a kite between them
+        let found = patterns(&[
+            owned("Jane", Planet::Sun, 0.0),
+            owned("Jane", Planet::Moon, 120.0),
+            owned("Jane", Planet::Mars, 240.0),
+            owned("Tom", Planet::Saturn, 180.0),
+        ]);
+        assert_eq!(kinds(&found), vec![AspectPatternType::Kite]);
+        assert_eq!(
+            found[0].bodies.last().map(String::as_str),
+            Some("Tom: Saturn")
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Aspect, ZodiacSign};
+use super::{Aspect, LifeArea, ParallelAspect, ZodiacPosition, ZodiacSign};
 
 /// Request to get daily transits
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
@@ -17,13 +17,27 @@ pub struct Transit {
     pub sign: ZodiacSign,
     pub degree: f64,
     pub retrograde: bool,
+    /// Declination in degrees (negative = south)
+    pub declination: f64,
+    /// Declination beyond the Sun's greatest declination
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub out_of_bounds: bool,
     pub aspects_to_natal: Vec<Aspect>,
+    /// Parallels and contra-parallels to natal planets (when enabled in the orb policy)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parallels_to_natal: Vec<ParallelAspect>,
 }
 
 /// Response for daily transits
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct GetDailyTransitsResponse {
     pub date: String,
+    /// Exact moment (UTC) the positions are calculated for
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Zodiac the positions are expressed in
+    pub zodiac: String,
     pub transits: Vec<Transit>,
 }
 
@@ -39,7 +53,9 @@ pub struct GetRetrogradeStatusRequest {
     pub include_upcoming: Option<bool>,
 
     /// Number of days to look ahead for upcoming retrogrades
-    #[schemars(description = "Number of days to look ahead for upcoming retrogrades (default: 90)")]
+    #[schemars(
+        description = "Number of days to look ahead for upcoming retrogrades (default: 90)"
+    )]
     pub days_ahead: Option<i64>,
 }
 
@@ -48,24 +64,63 @@ pub struct GetRetrogradeStatusRequest {
 pub struct RetrogradeInfo {
     pub planet: String,
     pub retrograde: bool,
+    /// Exact station times are ISO-8601 timestamps in UTC
     pub retrograde_start: Option<String>,
     pub retrograde_end: Option<String>,
     pub direct_station: Option<String>,
+    /// Station times in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrograde_start_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrograde_end_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_station_local: Option<String>,
+    /// Where the planet stationed retrograde and where it stations direct (tropical)
+    pub retrograde_station_position: Option<ZodiacPosition>,
+    pub direct_station_position: Option<ZodiacPosition>,
+    /// When the planet first reached the direct-station degree
+    pub pre_shadow_start: Option<String>,
+    /// When the planet clears the retrograde-station degree
+    pub post_shadow_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_shadow_start_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_shadow_end_local: Option<String>,
 }
 
 /// Information about an upcoming retrograde
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct UpcomingRetrograde {
     pub planet: String,
+    /// Exact station times are ISO-8601 timestamps in UTC
     pub retrograde_start: String,
-    pub retrograde_end: String,
+    /// None when the direct station is too far ahead to find
+    pub retrograde_end: Option<String>,
+    /// Station times in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrograde_start_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrograde_end_local: Option<String>,
     pub days_until: i64,
+    /// Where the planet stations retrograde and direct (tropical)
+    pub retrograde_station_position: ZodiacPosition,
+    pub direct_station_position: Option<ZodiacPosition>,
+    /// When the planet first reaches the direct-station degree
+    pub pre_shadow_start: Option<String>,
+    /// When the planet clears the retrograde-station degree
+    pub post_shadow_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_shadow_start_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_shadow_end_local: Option<String>,
 }
 
 /// Response for retrograde status
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct GetRetrogradeStatusResponse {
     pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     pub currently_retrograde: Vec<RetrogradeInfo>,
     pub upcoming_retrogrades: Vec<UpcomingRetrograde>,
 }
@@ -93,8 +148,40 @@ pub struct MajorEvent {
     pub event: String,
     #[serde(rename = "type")]
     pub event_type: String,
+    /// Exact time (UTC) for events found by root-finding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Exact time in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_time: Option<String>,
     pub orb: Option<f64>,
     pub affected_planets: Vec<String>,
+    /// Orb window and exact perfections for transit-to-natal aspects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<AspectWindow>,
+    /// Natal house entered, for house ingresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house: Option<u8>,
+    /// Life area of the natal house entered, for house ingresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_area: Option<LifeArea>,
+}
+
+/// Orb window of a transit-to-natal aspect, clipped to the report range
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub struct AspectWindow {
+    /// When the transit comes within orb (null if already in orb when the range starts)
+    pub enters_orb: Option<String>,
+    /// When the transit leaves orb (null if still in orb when the range ends)
+    pub leaves_orb: Option<String>,
+    /// Exact perfections (UTC); a retrograde transit can perfect up to three times
+    pub exact: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enters_orb_local: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leaves_orb_local: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exact_local: Vec<String>,
 }
 
 /// A lunar event
@@ -104,12 +191,27 @@ pub struct LunarEvent {
     pub event: String,
     #[serde(rename = "type")]
     pub event_type: String,
+    /// Exact time (UTC), or the start time for events that span a period
+    pub time: String,
+    /// Exact end time (UTC) for events that span a period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    /// Exact time in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_time: Option<String>,
+    /// End time in the requested timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_local_time: Option<String>,
 }
 
 /// Response for transit report
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct GetTransitReportResponse {
     pub period: DateRange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Zodiac used for sign ingresses and aspects
+    pub zodiac: String,
     pub major_events: Vec<MajorEvent>,
     pub lunar_events: Vec<LunarEvent>,
     pub retrograde_events: Vec<MajorEvent>,
@@ -74,6 +74,25 @@ impl ZodiacSign {
         Self::from_index(self.index() + 1)
     }
 
+    /// Get the element of this sign
+    pub fn element(&self) -> Element {
+        match self.index() % 4 {
+            0 => Element::Fire,
+            1 => Element::Earth,
+            2 => Element::Air,
+            _ => Element::Water,
+        }
+    }
+
+    /// Get the modality of this sign
+    pub fn modality(&self) -> Modality {
+        match self.index() % 3 {
+            0 => Modality::Cardinal,
+            1 => Modality::Fixed,
+            _ => Modality::Mutable,
+        }
+    }
+
     /// Get the traditional ruling planet for this sign
     pub fn ruler(&self) -> Planet {
         match self {
@@ -88,7 +107,7 @@ impl ZodiacSign {
             ZodiacSign::Sagittarius => Planet::Jupiter,
             ZodiacSign::Capricorn => Planet::Saturn,
             ZodiacSign::Aquarius => Planet::Uranus, // Modern ruler (traditional: Saturn)
-            ZodiacSign::Pisces => Planet::Neptune, // Modern ruler (traditional: Jupiter)
+            ZodiacSign::Pisces => Planet::Neptune,  // Modern ruler (traditional: Jupiter)
         }
     }
 }
@@ -113,6 +132,48 @@ impl fmt::Display for ZodiacSign {
     }
 }
 
+/// The four elements (triplicities)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Element {
+    Fire,
+    Earth,
+    Air,
+    Water,
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Element::Fire => "Fire",
+            Element::Earth => "Earth",
+            Element::Air => "Air",
+            Element::Water => "Water",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The three modalities (quadruplicities)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Modality {
+    Cardinal,
+    Fixed,
+    Mutable,
+}
+
+impl fmt::Display for Modality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Modality::Cardinal => "Cardinal",
+            Modality::Fixed => "Fixed",
+            Modality::Mutable => "Mutable",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// Celestial bodies used in astrological calculations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -129,6 +190,14 @@ pub enum Planet {
     Pluto,
     /// North Node (True Lunar Node) - the ascending lunar node
     NorthNode,
+    /// Mean Lunar Node - the node's averaged position
+    MeanNode,
+    /// South Node - the descending node, opposite the True North Node
+    SouthNode,
+    /// Black Moon Lilith - the Moon's mean apogee
+    MeanLilith,
+    /// True (osculating) Lilith - the Moon's osculating apogee
+    TrueLilith,
 }
 
 impl Planet {
@@ -149,32 +218,55 @@ impl Planet {
         ]
     }
 
+    /// Additional points that are calculated for every chart but only used when selected
+    pub fn additional() -> &'static [Planet] {
+        &[
+            Planet::MeanNode,
+            Planet::SouthNode,
+            Planet::MeanLilith,
+            Planet::TrueLilith,
+        ]
+    }
+
     /// Get Swiss Ephemeris body ID
+    /// The South Node has none of its own; it is the True Node reflected by 180°.
     pub fn swe_id(&self) -> i32 {
         match self {
-            Planet::Sun => 0,       // SE_SUN
-            Planet::Moon => 1,      // SE_MOON
-            Planet::Mercury => 2,   // SE_MERCURY
-            Planet::Venus => 3,     // SE_VENUS
-            Planet::Mars => 4,      // SE_MARS
-            Planet::Jupiter => 5,   // SE_JUPITER
-            Planet::Saturn => 6,    // SE_SATURN
-            Planet::Uranus => 7,    // SE_URANUS
-            Planet::Neptune => 8,   // SE_NEPTUNE
-            Planet::Pluto => 9,     // SE_PLUTO
-            Planet::NorthNode => 11, // SE_TRUE_NODE (True Lunar Node)
+            Planet::Sun => 0,         // SE_SUN
+            Planet::Moon => 1,        // SE_MOON
+            Planet::Mercury => 2,     // SE_MERCURY
+            Planet::Venus => 3,       // SE_VENUS
+            Planet::Mars => 4,        // SE_MARS
+            Planet::Jupiter => 5,     // SE_JUPITER
+            Planet::Saturn => 6,      // SE_SATURN
+            Planet::Uranus => 7,      // SE_URANUS
+            Planet::Neptune => 8,     // SE_NEPTUNE
+            Planet::Pluto => 9,       // SE_PLUTO
+            Planet::NorthNode => 11,  // SE_TRUE_NODE (True Lunar Node)
+            Planet::MeanNode => 10,   // SE_MEAN_NODE
+            Planet::SouthNode => 11,  // SE_TRUE_NODE, reflected
+            Planet::MeanLilith => 12, // SE_MEAN_APOG
+            Planet::TrueLilith => 13, // SE_OSCU_APOG
         }
     }
 
     /// Whether this body can be retrograde
-    /// Sun and Moon cannot retrograde; North Node is always retrograde (apparent motion)
+    /// Sun and Moon cannot retrograde, nor can the mean apogee; the nodes are always retrograde (apparent motion)
     pub fn can_retrograde(&self) -> bool {
-        !matches!(self, Planet::Sun | Planet::Moon)
+        !matches!(self, Planet::Sun | Planet::Moon | Planet::MeanLilith)
+    }
+
+    /// Whether this is one of the two Black Moon Liliths
+    pub fn is_lilith(&self) -> bool {
+        matches!(self, Planet::MeanLilith | Planet::TrueLilith)
     }
 
     /// Whether this is a lunar node (for special handling)
     pub fn is_lunar_node(&self) -> bool {
-        matches!(self, Planet::NorthNode)
+        matches!(
+            self,
+            Planet::NorthNode | Planet::MeanNode | Planet::SouthNode
+        )
     }
 }
 
@@ -192,11 +284,247 @@ impl fmt::Display for Planet {
             Planet::Neptune => "Neptune",
             Planet::Pluto => "Pluto",
             Planet::NorthNode => "North Node",
+            Planet::MeanNode => "Mean Node",
+            Planet::SouthNode => "South Node",
+            Planet::MeanLilith => "Mean Lilith",
+            Planet::TrueLilith => "True Lilith",
         };
         write!(f, "{}", name)
     }
 }
 
+/// Any body Swiss Ephemeris can calculate: the planets and points above, the main
+/// asteroids and centaurs, or a minor planet by catalogue number
+/// Everything beyond the planets, nodes and Lilith needs the `.se1` files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Body {
+    Planet(Planet),
+    Chiron,
+    Pholus,
+    Ceres,
+    Pallas,
+    Juno,
+    Vesta,
+    /// Minor planet by catalogue number (e.g., 433 for Eros)
+    Asteroid(u32),
+}
+
+impl Body {
+    /// Swiss Ephemeris body ID (numbered asteroids are offset by SE_AST_OFFSET)
+    pub fn swe_id(&self) -> i32 {
+        match self {
+            Body::Planet(planet) => planet.swe_id(),
+            Body::Chiron => 15, // SE_CHIRON
+            Body::Pholus => 16, // SE_PHOLUS
+            Body::Ceres => 17,  // SE_CERES
+            Body::Pallas => 18, // SE_PALLAS
+            Body::Juno => 19,   // SE_JUNO
+            Body::Vesta => 20,  // SE_VESTA
+            Body::Asteroid(number) => 10000 + *number as i32,
+        }
+    }
+
+    /// The body for a minor planet number, using the named variant when there is one
+    pub fn from_number(number: u32) -> Option<Self> {
+        match number {
+            0 => None,
+            1 => Some(Body::Ceres),
+            2 => Some(Body::Pallas),
+            3 => Some(Body::Juno),
+            4 => Some(Body::Vesta),
+            2060 => Some(Body::Chiron),
+            5145 => Some(Body::Pholus),
+            n if n <= 999_999 => Some(Body::Asteroid(n)),
+            _ => None,
+        }
+    }
+
+    /// Parse a body name ("chiron", "north_node", "Mean Lilith") or an asteroid number ("433")
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase().replace([' ', '-'], "_");
+        if let Ok(number) = name.parse::<u32>() {
+            return Self::from_number(number);
+        }
+        let named = match name.as_str() {
+            "chiron" => Some(Body::Chiron),
+            "pholus" => Some(Body::Pholus),
+            "ceres" => Some(Body::Ceres),
+            "pallas" => Some(Body::Pallas),
+            "juno" => Some(Body::Juno),
+            "vesta" => Some(Body::Vesta),
+            _ => None,
+        };
+        named.or_else(|| {
+            Planet::all()
+                .iter()
+                .chain(Planet::additional())
+                .find(|planet| planet.to_string().to_lowercase().replace(' ', "_") == name)
+                .map(|planet| Body::Planet(*planet))
+        })
+    }
+
+    /// The planet behind this body, if it is one
+    pub fn planet(&self) -> Option<Planet> {
+        match self {
+            Body::Planet(planet) => Some(*planet),
+            _ => None,
+        }
+    }
+
+    /// Whether this body can be retrograde (every asteroid can)
+    pub fn can_retrograde(&self) -> bool {
+        self.planet().is_none_or(|planet| planet.can_retrograde())
+    }
+
+    /// The chart point for this body
+    pub fn chart_point(&self) -> ChartPoint {
+        match self {
+            Body::Planet(planet) => ChartPoint::Planet(*planet),
+            _ => ChartPoint::Body(*self),
+        }
+    }
+}
+
+impl fmt::Display for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Body::Planet(planet) => write!(f, "{}", planet),
+            Body::Chiron => write!(f, "Chiron"),
+            Body::Pholus => write!(f, "Pholus"),
+            Body::Ceres => write!(f, "Ceres"),
+            Body::Pallas => write!(f, "Pallas"),
+            Body::Juno => write!(f, "Juno"),
+            Body::Vesta => write!(f, "Vesta"),
+            Body::Asteroid(number) => write!(f, "Asteroid {}", number),
+        }
+    }
+}
+
+impl Serialize for Body {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Body::Asteroid(number) => serializer.serialize_u32(*number),
+            _ => serializer.serialize_str(&self.to_string().to_lowercase().replace(' ', "_")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Body {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum BodyRepr {
+            Number(u32),
+            Name(String),
+        }
+
+        let body = match BodyRepr::deserialize(deserializer)? {
+            BodyRepr::Number(number) => Body::from_number(number),
+            BodyRepr::Name(name) => Body::from_name(&name),
+        };
+        body.ok_or_else(|| {
+            serde::de::Error::custom(
+                "unknown body: expected a planet or point name, 'chiron', 'pholus', 'ceres', 'pallas', 'juno', 'vesta', or an asteroid number",
+            )
+        })
+    }
+}
+
+impl schemars::JsonSchema for Body {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Body".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A planet or point name (e.g., 'sun', 'north_node', 'mean_lilith'), 'chiron', 'pholus', 'ceres', 'pallas', 'juno', 'vesta', or a minor planet number (e.g., 433 for Eros)",
+            "anyOf": [
+                { "type": "string" },
+                { "type": "integer", "minimum": 1 }
+            ]
+        })
+    }
+}
+
+/// Optional points that can be added to natal charts, transits and synastry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AdditionalPoint {
+    MeanNode,
+    SouthNode,
+    /// Black Moon Lilith (mean apogee)
+    MeanLilith,
+    /// Osculating apogee
+    TrueLilith,
+    PartOfFortune,
+    PartOfSpirit,
+}
+
+impl AdditionalPoint {
+    /// The body behind this point, for points that move on their own (not the Arabic parts)
+    pub fn planet(&self) -> Option<Planet> {
+        match self {
+            AdditionalPoint::MeanNode => Some(Planet::MeanNode),
+            AdditionalPoint::SouthNode => Some(Planet::SouthNode),
+            AdditionalPoint::MeanLilith => Some(Planet::MeanLilith),
+            AdditionalPoint::TrueLilith => Some(Planet::TrueLilith),
+            AdditionalPoint::PartOfFortune | AdditionalPoint::PartOfSpirit => None,
+        }
+    }
+
+    /// The natal chart point for this selection
+    pub fn chart_point(&self) -> ChartPoint {
+        match self {
+            AdditionalPoint::MeanNode => ChartPoint::Planet(Planet::MeanNode),
+            AdditionalPoint::SouthNode => ChartPoint::Planet(Planet::SouthNode),
+            AdditionalPoint::MeanLilith => ChartPoint::Planet(Planet::MeanLilith),
+            AdditionalPoint::TrueLilith => ChartPoint::Planet(Planet::TrueLilith),
+            AdditionalPoint::PartOfFortune => ChartPoint::PartOfFortune,
+            AdditionalPoint::PartOfSpirit => ChartPoint::PartOfSpirit,
+        }
+    }
+}
+
+/// A point in a natal chart that can receive aspects: a planet, an angle or a house cusp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChartPoint {
+    Planet(Planet),
+    Ascendant,
+    Midheaven,
+    Vertex,
+    /// Cusp of the given house (1-12)
+    HouseCusp(u8),
+    /// Asteroid, centaur or other body outside the core planets
+    Body(Body),
+    PartOfFortune,
+    PartOfSpirit,
+}
+
+impl ChartPoint {
+    /// The planet behind this point, if it is one
+    pub fn planet(&self) -> Option<Planet> {
+        match self {
+            ChartPoint::Planet(planet) => Some(*planet),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ChartPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartPoint::Planet(planet) => write!(f, "{}", planet),
+            ChartPoint::Ascendant => write!(f, "Ascendant"),
+            ChartPoint::Midheaven => write!(f, "Midheaven"),
+            ChartPoint::Vertex => write!(f, "Vertex"),
+            ChartPoint::HouseCusp(house) => write!(f, "House {} cusp", house),
+            ChartPoint::Body(body) => write!(f, "{}", body),
+            ChartPoint::PartOfFortune => write!(f, "Part of Fortune"),
+            ChartPoint::PartOfSpirit => write!(f, "Part of Spirit"),
+        }
+    }
+}
+
 /// A position in the zodiac with sign and degree
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ZodiacPosition {
@@ -224,3 +552,256 @@ impl ZodiacPosition {
         format!("{}° {}", self.degree.round() as i32, self.sign)
     }
 }
+
+/// Zodiac reference frame
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ZodiacType {
+    /// Signs measured from the vernal equinox
+    #[default]
+    Tropical,
+    /// Signs measured against the fixed stars, offset by an ayanamsa
+    Sidereal,
+}
+
+/// Ayanamsa (precession offset) used for the sidereal zodiac
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Ayanamsa {
+    FaganBradley,
+    #[default]
+    Lahiri,
+    DeLuce,
+    Raman,
+    Ushashashi,
+    Krishnamurti,
+    DjwhalKhul,
+    Yukteshwar,
+    JnBhasin,
+    Aldebaran15Tau,
+    Hipparchos,
+    Sassanian,
+    GalacticCenter0Sag,
+    J2000,
+    J1900,
+    B1950,
+    SuryaSiddhanta,
+    Aryabhata,
+    SsRevati,
+    SsCitra,
+    TrueCitra,
+    TrueRevati,
+    TruePushya,
+    TrueMula,
+}
+
+impl Ayanamsa {
+    /// Get Swiss Ephemeris sidereal mode ID
+    pub fn swe_id(&self) -> i32 {
+        match self {
+            Ayanamsa::FaganBradley => 0,        // SE_SIDM_FAGAN_BRADLEY
+            Ayanamsa::Lahiri => 1,              // SE_SIDM_LAHIRI
+            Ayanamsa::DeLuce => 2,              // SE_SIDM_DELUCE
+            Ayanamsa::Raman => 3,               // SE_SIDM_RAMAN
+            Ayanamsa::Ushashashi => 4,          // SE_SIDM_USHASHASHI
+            Ayanamsa::Krishnamurti => 5,        // SE_SIDM_KRISHNAMURTI
+            Ayanamsa::DjwhalKhul => 6,          // SE_SIDM_DJWHAL_KHUL
+            Ayanamsa::Yukteshwar => 7,          // SE_SIDM_YUKTESHWAR
+            Ayanamsa::JnBhasin => 8,            // SE_SIDM_JN_BHASIN
+            Ayanamsa::Aldebaran15Tau => 14,     // SE_SIDM_ALDEBARAN_15TAU
+            Ayanamsa::Hipparchos => 15,         // SE_SIDM_HIPPARCHOS
+            Ayanamsa::Sassanian => 16,          // SE_SIDM_SASSANIAN
+            Ayanamsa::GalacticCenter0Sag => 17, // SE_SIDM_GALCENT_0SAG
+            Ayanamsa::J2000 => 18,              // SE_SIDM_J2000
+            Ayanamsa::J1900 => 19,              // SE_SIDM_J1900
+            Ayanamsa::B1950 => 20,              // SE_SIDM_B1950
+            Ayanamsa::SuryaSiddhanta => 21,     // SE_SIDM_SURYASIDDHANTA
+            Ayanamsa::Aryabhata => 23,          // SE_SIDM_ARYABHATA
+            Ayanamsa::SsRevati => 25,           // SE_SIDM_SS_REVATI
+            Ayanamsa::SsCitra => 26,            // SE_SIDM_SS_CITRA
+            Ayanamsa::TrueCitra => 27,          // SE_SIDM_TRUE_CITRA
+            Ayanamsa::TrueRevati => 28,         // SE_SIDM_TRUE_REVATI
+            Ayanamsa::TruePushya => 29,         // SE_SIDM_TRUE_PUSHYA
+            Ayanamsa::TrueMula => 35,           // SE_SIDM_TRUE_MULA
+        }
+    }
+}
+
+impl fmt::Display for Ayanamsa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Ayanamsa::FaganBradley => "Fagan-Bradley",
+            Ayanamsa::Lahiri => "Lahiri",
+            Ayanamsa::DeLuce => "De Luce",
+            Ayanamsa::Raman => "Raman",
+            Ayanamsa::Ushashashi => "Usha-Shashi",
+            Ayanamsa::Krishnamurti => "Krishnamurti",
+            Ayanamsa::DjwhalKhul => "Djwhal Khul",
+            Ayanamsa::Yukteshwar => "Yukteshwar",
+            Ayanamsa::JnBhasin => "J.N. Bhasin",
+            Ayanamsa::Aldebaran15Tau => "Aldebaran at 15 Taurus",
+            Ayanamsa::Hipparchos => "Hipparchos",
+            Ayanamsa::Sassanian => "Sassanian",
+            Ayanamsa::GalacticCenter0Sag => "Galactic Center at 0 Sagittarius",
+            Ayanamsa::J2000 => "J2000",
+            Ayanamsa::J1900 => "J1900",
+            Ayanamsa::B1950 => "B1950",
+            Ayanamsa::SuryaSiddhanta => "Surya Siddhanta",
+            Ayanamsa::Aryabhata => "Aryabhata",
+            Ayanamsa::SsRevati => "Surya Siddhanta (Revati)",
+            Ayanamsa::SsCitra => "Surya Siddhanta (Citra)",
+            Ayanamsa::TrueCitra => "True Citra",
+            Ayanamsa::TrueRevati => "True Revati",
+            Ayanamsa::TruePushya => "True Pushya",
+            Ayanamsa::TrueMula => "True Mula",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Zodiac used for a calculation: tropical, or sidereal with an ayanamsa
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZodiacMode {
+    #[default]
+    Tropical,
+    Sidereal(Ayanamsa),
+}
+
+impl ZodiacMode {
+    /// Resolve optional tool inputs into a zodiac mode
+    /// Returns None when neither is given so callers can fall back to a chart's own zodiac.
+    /// An ayanamsa on its own implies the sidereal zodiac; sidereal without one uses Lahiri.
+    pub fn from_options(zodiac: Option<ZodiacType>, ayanamsa: Option<Ayanamsa>) -> Option<Self> {
+        match (zodiac, ayanamsa) {
+            (None, None) => None,
+            (Some(ZodiacType::Tropical), _) => Some(ZodiacMode::Tropical),
+            (_, ayanamsa) => Some(ZodiacMode::Sidereal(ayanamsa.unwrap_or_default())),
+        }
+    }
+
+    pub fn zodiac_type(&self) -> ZodiacType {
+        match self {
+            ZodiacMode::Tropical => ZodiacType::Tropical,
+            ZodiacMode::Sidereal(_) => ZodiacType::Sidereal,
+        }
+    }
+
+    pub fn ayanamsa(&self) -> Option<Ayanamsa> {
+        match self {
+            ZodiacMode::Tropical => None,
+            ZodiacMode::Sidereal(ayanamsa) => Some(*ayanamsa),
+        }
+    }
+
+    /// Equinox or solstice marked by the Sun entering a sign
+    /// Only tropical signs are tied to the seasons; sidereal ingresses come weeks later.
+    pub fn season_start(&self, sign: ZodiacSign) -> Option<&'static str> {
+        if *self != ZodiacMode::Tropical {
+            return None;
+        }
+        match sign {
+            ZodiacSign::Aries => Some("Spring Equinox"),
+            ZodiacSign::Cancer => Some("Summer Solstice"),
+            ZodiacSign::Libra => Some("Fall Equinox"),
+            ZodiacSign::Capricorn => Some("Winter Solstice"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ZodiacMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZodiacMode::Tropical => write!(f, "Tropical"),
+            ZodiacMode::Sidereal(ayanamsa) => write!(f, "Sidereal ({})", ayanamsa),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_body_from_name() {
+        assert_eq!(Body::from_name("Chiron"), Some(Body::Chiron));
+        assert_eq!(
+            Body::from_name(" north node "),
+            Some(Body::Planet(Planet::NorthNode))
+        );
+        assert_eq!(
+            Body::from_name("Mean-Lilith"),
+            Some(Body::Planet(Planet::MeanLilith))
+        );
+        assert_eq!(
+            Body::from_name("south_node"),
+            Some(Body::Planet(Planet::SouthNode))
+        );
+        assert_eq!(Body::from_name("433"), Some(Body::Asteroid(433)));
+        assert_eq!(Body::from_name("Nibiru"), None);
+    }
+
+    #[test]
+    fn test_body_from_number() {
+        // Numbers with a named variant map to it, so both spellings compare equal
+        assert_eq!(Body::from_number(1), Some(Body::Ceres));
+        assert_eq!(Body::from_number(2060), Some(Body::Chiron));
+        assert_eq!(Body::from_number(5145), Some(Body::Pholus));
+        assert_eq!(Body::from_number(433), Some(Body::Asteroid(433)));
+        assert_eq!(Body::from_number(0), None);
+        assert_eq!(Body::from_number(1_000_000), None);
+        assert_eq!(Body::Asteroid(433).swe_id(), 10433);
+    }
+
+    #[test]
+    fn test_season_start_only_in_tropical_zodiac() {
+        assert_eq!(
+            ZodiacMode::Tropical.season_start(ZodiacSign::Aries),
+            Some("Spring Equinox")
+        );
+        assert_eq!(
+            ZodiacMode::Tropical.season_start(ZodiacSign::Capricorn),
+            Some("Winter Solstice")
+        );
+        assert_eq!(ZodiacMode::Tropical.season_start(ZodiacSign::Taurus), None);
+        assert_eq!(
+            ZodiacMode::Sidereal(Ayanamsa::Lahiri).season_start(ZodiacSign::Aries),
+            None
+        );
+    }
+
+    #[test]
+    fn test_chart_points() {
+        assert_eq!(
+            ChartPoint::Planet(Planet::Venus).planet(),
+            Some(Planet::Venus)
+        );
+        assert_eq!(ChartPoint::Ascendant.planet(), None);
+        assert_eq!(Body::Chiron.chart_point().planet(), None);
+        assert_eq!(
+            Body::Planet(Planet::Pluto).chart_point(),
+            ChartPoint::Planet(Planet::Pluto)
+        );
+
+        assert_eq!(
+            AdditionalPoint::SouthNode.chart_point(),
+            ChartPoint::Planet(Planet::SouthNode)
+        );
+        assert_eq!(
+            AdditionalPoint::TrueLilith.chart_point(),
+            ChartPoint::Planet(Planet::TrueLilith)
+        );
+        assert_eq!(
+            AdditionalPoint::PartOfSpirit.chart_point(),
+            ChartPoint::PartOfSpirit
+        );
+
+        assert_eq!(ChartPoint::HouseCusp(4).to_string(), "House 4 cusp");
+        assert_eq!(ChartPoint::PartOfFortune.to_string(), "Part of Fortune");
+        assert_eq!(ChartPoint::Midheaven.to_string(), "Midheaven");
+    }
+}
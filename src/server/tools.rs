@@ -1,35 +1,59 @@
 //! MCP Server tools for astrological calculations
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use chrono::NaiveDate;
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rmcp::{
-    RoleServer, ServerHandler,
     model::{
         CallToolRequestParam, CallToolResult, Content, Implementation, ListToolsResult,
         PaginatedRequestParam, ProtocolVersion, ServerCapabilities, ServerInfo, Tool,
     },
     schemars::{self, schema_for},
     service::RequestContext,
+    RoleServer, ServerHandler,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
 
+use crate::ephemeris::calculator::angle_past;
 use crate::ephemeris::{
-    calc_all_planets, calc_houses, calc_planet_position, calc_sun_moon_angle, date_to_julian_day,
-    find_next_full_moon, find_next_new_moon, find_next_sign_ingress, find_next_station,
-    house_system_name, julian_day_to_date, local_datetime_to_julian_day, planet_in_house,
-    HOUSE_PLACIDUS,
+    bundled_star_names, calc_all_planets, calc_ayanamsa, calc_body_position, calc_fixed_star,
+    calc_houses_with_fallback, calc_obliquity, calc_planet_position, calc_planets,
+    calc_sun_moon_angle, conjunction_or_opposition, datetime_to_julian_day,
+    find_current_retrograde_cycle, find_eclipse, find_eclipse_path, find_eclipse_sequence,
+    find_eclipses, find_house_ingresses, find_lunar_eclipse, find_next_full_moon,
+    find_next_moon_aspect, find_next_new_moon, find_next_station, find_previous_full_moon,
+    find_previous_new_moon, find_retrograde_cycle, find_sign_ingresses, find_solar_eclipse,
+    find_stations, find_transit_aspect_windows, find_transit_parallel_windows, find_void_of_course,
+    find_void_periods, julian_day_to_date, julian_day_to_local_timestamp, julian_day_to_timestamp,
+    local_eclipse, planet_in_house, prenatal_eclipses, resolve_local_datetime,
+    resolve_query_datetime, Eclipse, EclipsePath, FixedStarPosition, GeoPoint, LocalTimezone,
+    PlanetaryPosition, TransitAspect, DEFAULT_ECLIPSE_ORB, DEFAULT_FIXED_STAR_ORB,
 };
 use crate::models::{
-    find_aspect, Aspect, DateRange, GetDailyTransitsResponse, GetLunarInfoResponse,
-    GetRetrogradeStatusResponse, GetTransitReportResponse, HouseCusps, LifeArea, LunarCycle,
-    LunarEvent, LunarPhase, LunarPhaseName, MajorEvent, NatalChart, NatalChartSummary, Planet,
-    PlanetPosition, RetrogradeInfo, StoreNatalChartRequest, StoreNatalChartResponse, Transit,
-    UpcomingRetrograde, VoidOfCourse, ZodiacPosition,
+    find_aspect_patterns, orb_rate, AdditionalPoint, AmbiguousTimePolicy, Aspect, AspectType,
+    AspectWindow, Ayanamsa, Body, ChartPoint, DateRange, DeclinationAspect, EclipseKind,
+    EclipseSummary, GetDailyTransitsResponse, GetLunarInfoResponse, GetRetrogradeStatusResponse,
+    GetTransitReportResponse, GetVoidOfCourseCalendarResponse, HouseCusps, HouseFallbackPolicy,
+    HouseSystem, HouseSystemFallback, LifeArea, LocalEclipseCircumstances, LocalEclipseContact,
+    LunarCycle, LunarEvent, LunarPhase, LunarPhaseName, MajorEvent, NatalAspect, NatalChart,
+    NatalChartSummary, NatalEclipse, NatalEclipseContact, OrbMotion, OrbPolicy, OrbSettings,
+    ParallelAspect, PatternBody, PatternSource, Planet, PlanetPosition, RetrogradeInfo,
+    SearchDirection, StoreNatalChartRequest, StoreNatalChartResponse, Transit, UpcomingRetrograde,
+    VoidOfCourse, VoidOfCoursePeriod, VoidOfCourseRule, ZodiacMode, ZodiacPosition, ZodiacType,
 };
 use crate::storage::Storage;
 
+/// Longest range the void-of-course calendar covers in one call, in days
+const MAX_VOID_CALENDAR_DAYS: i64 = 366;
+
+/// Longest range the transit report covers in one call, in days
+const MAX_TRANSIT_REPORT_DAYS: i64 = 366;
+
+/// Longest range of eclipses listed in one call, in days (ten years)
+const MAX_ECLIPSE_RANGE_DAYS: i64 = 3653;
+
 /// Input for storing a natal chart
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
 pub struct StoreNatalChartInput {
@@ -45,8 +69,28 @@ pub struct StoreNatalChartInput {
     pub latitude: f64,
     #[schemars(description = "Longitude of birth location")]
     pub longitude: f64,
-    #[schemars(description = "Timezone identifier (e.g., 'America/Panama')")]
+    #[schemars(
+        description = "Timezone: an IANA identifier (e.g., 'America/Panama'), a UTC offset (e.g., '+05:30'), or 'LMT' for local mean time from the longitude"
+    )]
     pub timezone: String,
+    #[schemars(description = "Zodiac: 'tropical' (default) or 'sidereal'")]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (e.g., 'lahiri', 'raman', 'krishnamurti', 'fagan_bradley'). Default: 'lahiri'"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+    #[schemars(
+        description = "House system (e.g., 'placidus', 'koch', 'whole_sign', 'equal', 'porphyry', 'regiomontanus', 'campanus'). Default: 'placidus'"
+    )]
+    pub house_system: Option<HouseSystem>,
+    #[schemars(
+        description = "What to do when the house system is undefined at the birth latitude (Placidus/Koch inside the polar circles): 'porphyry' (default), 'whole_sign', 'equal' or 'reject'"
+    )]
+    pub polar_fallback: Option<HouseFallbackPolicy>,
+    #[schemars(
+        description = "Birth time repeated when clocks fall back: 'earlier' (first occurrence, daylight time), 'later' (second occurrence, standard time) or 'reject' (default, report the ambiguity)"
+    )]
+    pub ambiguous_time: Option<AmbiguousTimePolicy>,
 }
 
 /// Input for daily transits
@@ -54,6 +98,36 @@ pub struct StoreNatalChartInput {
 pub struct DailyTransitsInput {
     #[schemars(description = "Date to get transits for in YYYY-MM-DD format")]
     pub date: String,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for the date and time (e.g., 'Asia/Tokyo'). Defaults to the natal chart's timezone, or UTC without one"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Zodiac: 'tropical' or 'sidereal' (defaults to the natal chart's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the natal chart's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+    #[schemars(
+        description = "Orb policy for this call: enabled aspects, orb method and orb overrides (defaults to the server's transit orb settings)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Also use the 12 house cusps as natal points, besides the Ascendant, Midheaven and Vertex (default: false)"
+    )]
+    pub include_house_cusps: Option<bool>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
+    #[schemars(
+        description = "Further bodies to include by name or minor planet number, e.g. ['chiron', 'ceres', 433]. Asteroids need the Swiss Ephemeris files (see STELLIUM_EPHE_PATH)"
+    )]
+    pub bodies: Option<Vec<Body>>,
 }
 
 /// Input for retrograde status
@@ -63,8 +137,14 @@ pub struct RetrogradeStatusInput {
     pub date: String,
     #[schemars(description = "Whether to include upcoming retrograde periods (default: true)")]
     pub include_upcoming: Option<bool>,
-    #[schemars(description = "Number of days to look ahead for upcoming retrogrades (default: 90)")]
+    #[schemars(
+        description = "Number of days to look ahead for upcoming retrogrades (default: 90)"
+    )]
     pub days_ahead: Option<i64>,
+    #[schemars(
+        description = "Timezone identifier for the date and local event times (e.g., 'America/New_York'). Without one the date is a UTC day. Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
 }
 
 /// Input for lunar info
@@ -72,6 +152,20 @@ pub struct RetrogradeStatusInput {
 pub struct LunarInfoInput {
     #[schemars(description = "Date to get lunar information for in YYYY-MM-DD format")]
     pub date: String,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(description = "Zodiac for the Moon's sign: 'tropical' (default) or 'sidereal'")]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(description = "Ayanamsa for the sidereal zodiac (default: 'lahiri')")]
+    pub ayanamsa: Option<Ayanamsa>,
+    #[schemars(
+        description = "Planets counted for void-of-course: 'modern' (Sun through Pluto, default) or 'traditional' (Sun through Saturn)"
+    )]
+    pub void_of_course_rule: Option<VoidOfCourseRule>,
+    #[schemars(
+        description = "Timezone identifier for the date and time and for local event times (e.g., 'America/New_York'). Defaults to the natal chart's timezone, or UTC without one. Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
 }
 
 /// Input for transit report
@@ -79,10 +173,69 @@ pub struct LunarInfoInput {
 pub struct TransitReportInput {
     #[schemars(description = "Start date in YYYY-MM-DD format")]
     pub start_date: String,
-    #[schemars(description = "End date in YYYY-MM-DD format")]
+    #[schemars(
+        description = "End date in YYYY-MM-DD format (inclusive, at most 366 days after start_date)"
+    )]
     pub end_date: String,
-    #[schemars(description = "Whether to include minor aspects (default: false)")]
+    #[schemars(
+        description = "Whether to include minor aspects and the quintile, septile and novile families (default: false)"
+    )]
     pub include_minor_aspects: Option<bool>,
+    #[schemars(
+        description = "Zodiac: 'tropical' or 'sidereal' (defaults to the natal chart's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the natal chart's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+    #[schemars(
+        description = "Planets counted for void-of-course Moon events: 'modern' (default) or 'traditional'"
+    )]
+    pub void_of_course_rule: Option<VoidOfCourseRule>,
+    #[schemars(
+        description = "Timezone identifier for the dates and local event times (e.g., 'America/New_York'). Without one the dates are UTC days. Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Orb policy for this call: enabled aspects, orb method and orb overrides (defaults to the server's transit orb settings)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Also use the 12 house cusps as natal points, besides the Ascendant, Midheaven and Vertex (default: false)"
+    )]
+    pub include_house_cusps: Option<bool>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
+    #[schemars(
+        description = "Also report the Moon's aspects to the natal chart and its natal house ingresses (default: false, as they recur every month)"
+    )]
+    pub include_moon: Option<bool>,
+}
+
+/// Input for the void-of-course calendar
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct VoidOfCourseCalendarInput {
+    #[schemars(description = "Start date in YYYY-MM-DD format")]
+    pub start_date: String,
+    #[schemars(
+        description = "End date in YYYY-MM-DD format (inclusive, at most 366 days after start_date)"
+    )]
+    pub end_date: String,
+    #[schemars(
+        description = "Timezone identifier for the dates and the local start/end times (e.g., 'America/New_York'). Without one the dates are UTC days. Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Planets counted for void-of-course: 'modern' (Sun through Pluto, default) or 'traditional' (Sun through Saturn)"
+    )]
+    pub void_of_course_rule: Option<VoidOfCourseRule>,
+    #[schemars(description = "Zodiac for sign ingresses: 'tropical' (default) or 'sidereal'")]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(description = "Ayanamsa for the sidereal zodiac (default: 'lahiri')")]
+    pub ayanamsa: Option<Ayanamsa>,
 }
 
 /// Input for getting a natal chart
@@ -90,6 +243,22 @@ pub struct TransitReportInput {
 pub struct GetNatalChartInput {
     #[schemars(description = "Name of the natal chart to retrieve")]
     pub name: String,
+    #[schemars(
+        description = "Render the chart in another house system without changing the stored chart (e.g., 'whole_sign', 'koch')"
+    )]
+    pub house_system: Option<HouseSystem>,
+    #[schemars(
+        description = "Orb policy for the natal aspect grid (defaults to the aspects stored with the chart, calculated with the server's natal orb settings)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
+    #[schemars(
+        description = "Further bodies to include by name or minor planet number, e.g. ['chiron', 'ceres', 433]. Asteroids need the Swiss Ephemeris files (see STELLIUM_EPHE_PATH)"
+    )]
+    pub bodies: Option<Vec<Body>>,
 }
 
 /// Input for deleting a natal chart
@@ -97,14 +266,18 @@ pub struct GetNatalChartInput {
 pub struct DeleteNatalChartInput {
     #[schemars(description = "Name of the natal chart to delete")]
     pub name: String,
-    #[schemars(description = "Birth date of the chart to delete (for confirmation) in YYYY-MM-DD format")]
+    #[schemars(
+        description = "Birth date of the chart to delete (for confirmation) in YYYY-MM-DD format"
+    )]
     pub birth_date: String,
 }
 
 /// Input for searching natal charts
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
 pub struct SearchNatalChartsInput {
-    #[schemars(description = "Search query - matches against name (case-insensitive, partial match)")]
+    #[schemars(
+        description = "Search query - matches against name (case-insensitive, partial match)"
+    )]
     pub query: String,
 }
 
@@ -115,8 +288,30 @@ pub struct GetCompatibilityInput {
     pub person1_name: String,
     #[schemars(description = "Name of the second person's natal chart")]
     pub person2_name: String,
-    #[schemars(description = "Include minor aspects (sextile, quincunx) - default: false")]
+    #[schemars(
+        description = "Include minor aspects (semi-sextile, quincunx, semi-square, sesquiquadrate) and the quintile, septile and novile families - default: false"
+    )]
     pub include_minor_aspects: Option<bool>,
+    #[schemars(
+        description = "Zodiac to compare both charts in (defaults to the first person's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the first person's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+    #[schemars(
+        description = "Orb policy for this call: enabled aspects, orb method and orb overrides (defaults to the server's synastry orb settings)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Also use the 12 house cusps as natal points, besides the Ascendant, Midheaven and Vertex (default: false)"
+    )]
+    pub include_house_cusps: Option<bool>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
 }
 
 /// Input for full chart analysis
@@ -126,6 +321,24 @@ pub struct GetFullChartAnalysisInput {
     pub name: String,
     #[schemars(description = "Date for transit analysis in YYYY-MM-DD format (defaults to today)")]
     pub date: Option<String>,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for the date and time (e.g., 'Asia/Tokyo'). Defaults to the natal chart's timezone, or UTC without one"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Orb policy for this call: enabled aspects, orb method and orb overrides (defaults to the server's transit orb settings)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Also use the 12 house cusps as natal points, besides the Ascendant, Midheaven and Vertex (default: false)"
+    )]
+    pub include_house_cusps: Option<bool>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
 }
 
 /// Input for relationship transit analysis
@@ -137,6 +350,206 @@ pub struct GetRelationshipTransitAnalysisInput {
     pub person2_name: String,
     #[schemars(description = "Date for transit analysis in YYYY-MM-DD format (defaults to today)")]
     pub date: Option<String>,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for the date and time (e.g., 'Europe/Paris'). Defaults to the first person's timezone"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Orb policy for the synastry aspects (defaults to the server's synastry orb settings)"
+    )]
+    pub synastry_orbs: Option<OrbPolicy>,
+    #[schemars(
+        description = "Orb policy for each person's transits (defaults to the server's transit orb settings)"
+    )]
+    pub transit_orbs: Option<OrbPolicy>,
+}
+
+/// Input for aspect pattern detection
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct GetAspectPatternsInput {
+    #[schemars(
+        description = "Name of the natal chart (the first person's for composite and synastry). Optional for transit patterns, where it supplies houses and the default timezone"
+    )]
+    pub name: Option<String>,
+    #[schemars(
+        description = "Positions to search: 'natal' (default), 'transit' (the sky at date/time), 'composite' (midpoint composite of two charts) or 'synastry' (both charts overlaid)"
+    )]
+    pub source: Option<PatternSource>,
+    #[schemars(description = "Name of the second person's natal chart (composite and synastry)")]
+    pub person2_name: Option<String>,
+    #[schemars(description = "Date for transit patterns in YYYY-MM-DD format (defaults to today)")]
+    pub date: Option<String>,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for the date and time. Defaults to the natal chart's timezone, or UTC without one"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Orb policy for the aspects forming patterns (defaults to the server's natal orb settings, or synastry settings for synastry)"
+    )]
+    pub orbs: Option<OrbPolicy>,
+}
+
+/// Input for the eclipse finder
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct GetEclipsesInput {
+    #[schemars(
+        description = "Date to search from in YYYY-MM-DD format, a local day in the timezone (defaults to today)"
+    )]
+    pub date: Option<String>,
+    #[schemars(
+        description = "List every eclipse from date up to this date (YYYY-MM-DD, inclusive, at most ten years after date) instead of the next or previous few"
+    )]
+    pub end_date: Option<String>,
+    #[schemars(
+        description = "Search direction from date: 'next' (default) or 'previous'. Ignored with end_date"
+    )]
+    pub direction: Option<SearchDirection>,
+    #[schemars(
+        description = "Number of eclipses to return (default: 4, max: 50). Ignored with end_date"
+    )]
+    pub count: Option<usize>,
+    #[schemars(description = "Only 'solar' or only 'lunar' eclipses (default: both)")]
+    pub kind: Option<EclipseKind>,
+    #[schemars(
+        description = "Timezone identifier for local times of greatest eclipse (e.g., 'America/New_York'). Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Zodiac for the eclipse degree: 'tropical' or 'sidereal' (defaults to the default chart's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the default chart's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+}
+
+/// Input for the local circumstances and path of one eclipse
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct GetEclipseCircumstancesInput {
+    #[schemars(
+        description = "Date of the eclipse in YYYY-MM-DD format: the first eclipse with its greatest phase on or after local midnight of this date in the timezone is used. Defaults to today"
+    )]
+    pub date: Option<String>,
+    #[schemars(
+        description = "Only 'solar' or only 'lunar' eclipses (default: whichever comes first)"
+    )]
+    pub kind: Option<EclipseKind>,
+    #[schemars(
+        description = "Name of the natal chart whose birth location is the observer (defaults to the default chart)"
+    )]
+    pub name: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for local times (defaults to the natal chart's timezone). Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Include the solar eclipse path as a GeoJSON FeatureCollection (default: true)"
+    )]
+    pub include_path: Option<bool>,
+}
+
+/// Input for eclipses falling on a natal chart
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct GetNatalEclipsesInput {
+    #[schemars(description = "Name of the natal chart (defaults to the default chart)")]
+    pub name: Option<String>,
+    #[schemars(description = "Start date in YYYY-MM-DD format")]
+    pub start_date: String,
+    #[schemars(
+        description = "End date in YYYY-MM-DD format (inclusive, at most ten years after start_date)"
+    )]
+    pub end_date: String,
+    #[schemars(
+        description = "Orb in degrees of longitude between the eclipse degree and natal points (default: 3.0)"
+    )]
+    pub orb: Option<f64>,
+    #[schemars(description = "Also report eclipses opposite natal points (default: true)")]
+    pub include_oppositions: Option<bool>,
+    #[schemars(description = "Only 'solar' or only 'lunar' eclipses (default: both)")]
+    pub kind: Option<EclipseKind>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
+    #[schemars(
+        description = "Timezone identifier for the dates and local times of greatest eclipse (defaults to the natal chart's timezone). Times are always also given in UTC"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Zodiac: 'tropical' or 'sidereal' (defaults to the natal chart's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the natal chart's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+}
+
+/// Input for fixed star contacts
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct GetFixedStarsInput {
+    #[schemars(
+        description = "Name of the natal chart for natal contacts (defaults to the default chart; without one only star positions and transit contacts are given)"
+    )]
+    pub name: Option<String>,
+    #[schemars(
+        description = "Date for star positions and transit contacts in YYYY-MM-DD format (defaults to today)"
+    )]
+    pub date: Option<String>,
+    #[schemars(description = "Local time in HH:MM or HH:MM:SS format (default: 00:00)")]
+    pub time: Option<String>,
+    #[schemars(
+        description = "Timezone identifier for the date and time. Defaults to the natal chart's timezone, or UTC without one"
+    )]
+    pub timezone: Option<String>,
+    #[schemars(
+        description = "Stars by traditional name (e.g., ['Regulus', 'Spica', 'Algol']) or Bayer designation with a leading comma (e.g., ',alLeo'). Defaults to every star in the bundled catalog"
+    )]
+    pub stars: Option<Vec<String>>,
+    #[schemars(
+        description = "Orb in degrees of longitude for conjunctions and oppositions to stars (default: 1.0)"
+    )]
+    pub orb: Option<f64>,
+    #[schemars(description = "Also report oppositions to stars (default: true)")]
+    pub include_oppositions: Option<bool>,
+    #[schemars(
+        description = "Additional points to include: 'mean_node', 'south_node', 'mean_lilith', 'true_lilith', 'part_of_fortune', 'part_of_spirit' (default: none)"
+    )]
+    pub points: Option<Vec<AdditionalPoint>>,
+    #[schemars(
+        description = "Zodiac: 'tropical' or 'sidereal' (defaults to the natal chart's zodiac)"
+    )]
+    pub zodiac: Option<ZodiacType>,
+    #[schemars(
+        description = "Ayanamsa for the sidereal zodiac (defaults to the natal chart's ayanamsa)"
+    )]
+    pub ayanamsa: Option<Ayanamsa>,
+}
+
+/// Input for changing the default orb settings
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct SetOrbSettingsInput {
+    #[schemars(
+        description = "Orb policy for aspects within a natal chart (omit to keep the current one)"
+    )]
+    pub natal: Option<OrbPolicy>,
+    #[schemars(
+        description = "Orb policy for transits to natal planets (omit to keep the current one)"
+    )]
+    pub transit: Option<OrbPolicy>,
+    #[schemars(
+        description = "Orb policy for synastry between two charts (omit to keep the current one)"
+    )]
+    pub synastry: Option<OrbPolicy>,
+    #[schemars(
+        description = "Reset every table to the built-in defaults before applying the ones given (default: false)"
+    )]
+    pub reset: Option<bool>,
 }
 
 fn schema_to_value<T: schemars::JsonSchema>() -> Arc<serde_json::Map<String, Value>> {
@@ -156,6 +569,214 @@ fn empty_schema() -> Arc<serde_json::Map<String, Value>> {
     Arc::new(map)
 }
 
+/// Resolve a chart request's local birth date and time to a moment with its UTC offset
+fn birth_moment(request: &StoreNatalChartRequest) -> Result<DateTime<FixedOffset>, String> {
+    let date = request
+        .parse_date()
+        .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
+
+    let time = request
+        .parse_time()
+        .map_err(|e| format!("Invalid time format: {}. Expected HH:MM:SS", e))?;
+
+    let timezone = LocalTimezone::parse(&request.timezone, Some(request.longitude))
+        .map_err(|e| format!("Timezone error: {}", e))?;
+    resolve_local_datetime(
+        date,
+        time,
+        &timezone,
+        request.ambiguous_time.unwrap_or_default(),
+    )
+    .map_err(|e| format!("Timezone error: {}", e))
+}
+
+/// Julian Day of a stored chart's birth moment
+fn birth_julian_day(chart: &NatalChart) -> Result<f64, String> {
+    let request = chart_request(chart, chart.zodiac_mode(), chart.requested_house_system());
+    Ok(datetime_to_julian_day(birth_moment(&request)?.naive_utc()))
+}
+
+/// Calculate houses and planetary positions for a natal chart request
+fn calculate_natal_chart(request: &StoreNatalChartRequest) -> Result<NatalChart, String> {
+    let birth_moment = birth_moment(request)?;
+    let julian_day = datetime_to_julian_day(birth_moment.naive_utc());
+
+    let zodiac = request.zodiac_mode();
+    let house_system = request.house_system.unwrap_or_default();
+    let mut chart = NatalChart::new(request);
+    chart.utc_offset = Some(birth_moment.offset().to_string());
+
+    if let Some(ayanamsa) = zodiac.ayanamsa() {
+        chart.ayanamsa_value = Some(
+            calc_ayanamsa(ayanamsa, julian_day)
+                .map_err(|e| format!("Failed to calculate ayanamsa: {}", e))?,
+        );
+    }
+
+    // Calculate house positions (Placidus by default), falling back at polar latitudes
+    let fallback = request.polar_fallback.unwrap_or_default().fallback_system();
+    let (house_data, fallback_reason) = calc_houses_with_fallback(
+        julian_day,
+        request.latitude,
+        request.longitude,
+        house_system.code(),
+        fallback.map(|f| f.code()),
+        zodiac,
+    )
+    .map_err(|e| format!("Failed to calculate houses: {}", e))?;
+
+    let used_system = match (fallback_reason, fallback) {
+        (Some(reason), Some(fallback)) => {
+            chart.house_system_fallback = Some(HouseSystemFallback {
+                requested: house_system,
+                used: fallback,
+                reason,
+            });
+            fallback
+        }
+        _ => house_system,
+    };
+
+    // Store house cusps
+    chart.houses = Some(HouseCusps {
+        cusps: house_data
+            .cusps
+            .iter()
+            .map(|&lon| ZodiacPosition::from_longitude(lon))
+            .collect(),
+        system: used_system.name().to_string(),
+    });
+
+    chart.ascendant = Some(ZodiacPosition::from_longitude(house_data.ascendant));
+    chart.midheaven = Some(ZodiacPosition::from_longitude(house_data.midheaven));
+    chart.vertex = Some(ZodiacPosition::from_longitude(house_data.vertex));
+
+    // Calculate planetary positions, including the additional points (nodes and Liliths)
+    let bodies: Vec<Planet> = Planet::all()
+        .iter()
+        .chain(Planet::additional())
+        .copied()
+        .collect();
+    let positions = calc_planets(&bodies, julian_day, zodiac)
+        .map_err(|e| format!("Failed to calculate planetary positions: {}", e))?;
+
+    for (planet, position) in positions {
+        let zodiac_pos = position.to_zodiac_position();
+        let house = planet_in_house(position.longitude, &house_data.cusps);
+
+        // Store in legacy format for compatibility
+        chart.planets.insert(planet, zodiac_pos.clone());
+
+        // Store in new format with house placement
+        chart.planet_positions.insert(
+            planet,
+            PlanetPosition {
+                position: zodiac_pos,
+                house,
+                is_retrograde: position.is_retrograde,
+                speed: Some(position.speed_longitude),
+                declination: Some(position.declination),
+                right_ascension: Some(position.right_ascension),
+            },
+        );
+    }
+    chart.obliquity = Some(
+        calc_obliquity(julian_day).map_err(|e| format!("Failed to calculate obliquity: {}", e))?,
+    );
+
+    // Arabic parts, reversed for night births (Sun below the horizon)
+    if let (Some(sun), Some(moon)) = (
+        chart.planets.get(&Planet::Sun),
+        chart.planets.get(&Planet::Moon),
+    ) {
+        let ascendant = house_data.ascendant;
+        let is_day = (sun.longitude - ascendant).rem_euclid(360.0) >= 180.0;
+        let (fortune, spirit) = if is_day {
+            (
+                ascendant + moon.longitude - sun.longitude,
+                ascendant + sun.longitude - moon.longitude,
+            )
+        } else {
+            (
+                ascendant + sun.longitude - moon.longitude,
+                ascendant + moon.longitude - sun.longitude,
+            )
+        };
+        chart.is_day_chart = Some(is_day);
+        chart.part_of_fortune = Some(ZodiacPosition::from_longitude(fortune.rem_euclid(360.0)));
+        chart.part_of_spirit = Some(ZodiacPosition::from_longitude(spirit.rem_euclid(360.0)));
+    }
+
+    Ok(chart)
+}
+
+/// Recalculate a stored chart from its birth data in another zodiac or house system
+fn recalculate_chart(
+    chart: &NatalChart,
+    zodiac: ZodiacMode,
+    house_system: HouseSystem,
+) -> Result<NatalChart, String> {
+    calculate_natal_chart(&chart_request(chart, zodiac, house_system))
+}
+
+/// The request that reproduces a stored chart in the given zodiac and house system
+fn chart_request(
+    chart: &NatalChart,
+    zodiac: ZodiacMode,
+    house_system: HouseSystem,
+) -> StoreNatalChartRequest {
+    // Keep the chart's own polar fallback choice
+    let polar_fallback = match chart.house_system_fallback.as_ref().map(|f| f.used) {
+        Some(HouseSystem::WholeSign) => HouseFallbackPolicy::WholeSign,
+        Some(HouseSystem::Equal) => HouseFallbackPolicy::Equal,
+        _ => HouseFallbackPolicy::Porphyry,
+    };
+
+    StoreNatalChartRequest {
+        name: chart.name.clone(),
+        birth_date: chart.birth_date.clone(),
+        birth_time: chart.birth_time.clone(),
+        birth_location: chart.birth_location.clone(),
+        latitude: chart.latitude,
+        longitude: chart.longitude,
+        timezone: chart.timezone.clone(),
+        zodiac: Some(zodiac.zodiac_type()),
+        ayanamsa: zodiac.ayanamsa(),
+        house_system: Some(house_system),
+        polar_fallback: Some(polar_fallback),
+        ambiguous_time: chart.ambiguous_time,
+    }
+}
+
+/// The planets, followed by the selected additional points that move on their own
+fn transiting_bodies(extra: &[AdditionalPoint]) -> Vec<Planet> {
+    let mut bodies = Planet::all().to_vec();
+    for planet in extra.iter().filter_map(|point| point.planet()) {
+        if !bodies.contains(&planet) {
+            bodies.push(planet);
+        }
+    }
+    bodies
+}
+
+/// Return the chart expressed in the given zodiac, recalculating only when it differs
+/// or when the chart was stored before natal speeds, declinations or the additional points were recorded
+fn chart_in_zodiac(chart: NatalChart, zodiac: ZodiacMode) -> Result<NatalChart, String> {
+    let has_speeds_and_declinations = chart
+        .planet_positions
+        .values()
+        .all(|p| p.speed.is_some() && p.declination.is_some());
+    let has_additional = Planet::additional()
+        .iter()
+        .all(|p| chart.planets.contains_key(p))
+        && chart.part_of_fortune.is_some();
+    if chart.zodiac_mode() == zodiac && has_speeds_and_declinations && has_additional {
+        Ok(chart)
+    } else {
+        recalculate_chart(&chart, zodiac, chart.requested_house_system())
+    }
+}
+
 /// MCP Server for astrological calculations
 #[derive(Clone)]
 pub struct StelliumServer {
@@ -164,8 +785,14 @@ pub struct StelliumServer {
 
 impl StelliumServer {
     pub fn new() -> Self {
-        let storage = Arc::new(Storage::new().expect("Failed to initialize storage"));
-        Self { storage }
+        Self::with_storage(Storage::new().expect("Failed to initialize storage"))
+    }
+
+    /// Create a server on the given storage
+    pub fn with_storage(storage: Storage) -> Self {
+        Self {
+            storage: Arc::new(storage),
+        }
     }
 
     fn store_natal_chart(&self, input: StoreNatalChartInput) -> String {
@@ -177,87 +804,43 @@ impl StelliumServer {
             latitude: input.latitude,
             longitude: input.longitude,
             timezone: input.timezone,
+            zodiac: input.zodiac,
+            ayanamsa: input.ayanamsa,
+            house_system: input.house_system,
+            polar_fallback: input.polar_fallback,
+            ambiguous_time: input.ambiguous_time,
         };
 
-        let date = match request.parse_date() {
-            Ok(d) => d,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
-        };
-
-        let time = match request.parse_time() {
-            Ok(t) => t,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid time format: {}. Expected HH:MM:SS", e)
-            }).to_string(),
-        };
-
-        let julian_day = match local_datetime_to_julian_day(date, time, &request.timezone) {
-            Ok(jd) => jd,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Timezone error: {}", e)
-            }).to_string(),
-        };
-
-        let mut chart = NatalChart::new(&request);
-
-        // Calculate house positions (Placidus by default)
-        let house_data = match calc_houses(julian_day, request.latitude, request.longitude, HOUSE_PLACIDUS) {
-            Ok(h) => h,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Failed to calculate houses: {}", e)
-            }).to_string(),
-        };
-
-        // Store house cusps
-        chart.houses = Some(HouseCusps {
-            cusps: house_data.cusps.iter().map(|&lon| ZodiacPosition::from_longitude(lon)).collect(),
-            system: house_system_name(HOUSE_PLACIDUS).to_string(),
-        });
-
-        chart.ascendant = Some(ZodiacPosition::from_longitude(house_data.ascendant));
-        chart.midheaven = Some(ZodiacPosition::from_longitude(house_data.midheaven));
-        chart.vertex = Some(ZodiacPosition::from_longitude(house_data.vertex));
-
-        // Calculate planetary positions
-        let positions = match calc_all_planets(julian_day) {
-            Ok(p) => p,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Failed to calculate planetary positions: {}", e)
-            }).to_string(),
+        let mut chart = match calculate_natal_chart(&request) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
-
-        for (planet, position) in positions {
-            let zodiac_pos = position.to_zodiac_position();
-            let house = planet_in_house(position.longitude, &house_data.cusps);
-
-            // Store in legacy format for compatibility
-            chart.planets.insert(planet, zodiac_pos.clone());
-
-            // Store in new format with house placement
-            chart.planet_positions.insert(planet, PlanetPosition {
-                position: zodiac_pos,
-                house,
-                is_retrograde: position.is_retrograde,
-            });
-        }
+        chart.aspects = chart.calculate_aspects(&self.storage.get_orb_settings().natal, &[]);
 
         if let Err(e) = self.storage.save_chart(chart.clone()) {
             return json!({
                 "success": false,
                 "error": format!("Failed to save chart: {}", e)
-            }).to_string();
+            })
+            .to_string();
         }
 
+        let warnings = chart
+            .house_system_fallback
+            .iter()
+            .map(|f| format!("{}; using {} houses instead", f.reason, f.used))
+            .collect();
+
         let response = StoreNatalChartResponse {
             success: true,
             message: "Natal chart stored successfully".to_string(),
+            warnings,
             natal_chart: NatalChartSummary::from(&chart),
         };
 
@@ -265,57 +848,178 @@ impl StelliumServer {
     }
 
     fn get_daily_transits(&self, input: DailyTransitsInput) -> String {
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         let parsed_date = match NaiveDate::parse_from_str(&input.date, "%Y-%m-%d") {
             Ok(d) => d,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
         };
 
         let natal_chart = self.storage.get_default_chart();
+        let timezone = input
+            .timezone
+            .or_else(|| natal_chart.as_ref().map(|c| c.timezone.clone()));
+        let longitude = natal_chart.as_ref().map(|c| c.longitude);
+        let julian_day = match query_julian_day(
+            parsed_date,
+            input.time.as_deref(),
+            timezone.as_deref(),
+            longitude,
+        ) {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .or_else(|| natal_chart.as_ref().map(|c| c.zodiac_mode()))
+            .unwrap_or_default();
+
+        // Natal positions must be in the same zodiac as the transiting ones
+        let natal_chart = match natal_chart.map(|c| chart_in_zodiac(c, zodiac)).transpose() {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
 
-        let julian_day = date_to_julian_day(parsed_date);
-        let positions = match calc_all_planets(julian_day) {
+        let extra = input.points.unwrap_or_default();
+        let positions = match calc_planets(&transiting_bodies(&extra), julian_day, zodiac) {
             Ok(p) => p,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Failed to calculate positions: {}", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate positions: {}", e)
+                })
+                .to_string()
+            }
         };
+        let mut positions: Vec<(Body, PlanetaryPosition)> = positions
+            .into_iter()
+            .map(|(planet, position)| (Body::Planet(planet), position))
+            .collect();
+        for body in input.bodies.unwrap_or_default() {
+            if positions.iter().any(|(b, _)| *b == body) {
+                continue;
+            }
+            match calc_body_position(body, julian_day, zodiac) {
+                Ok(position) => positions.push((body, position)),
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            }
+        }
 
+        let orbs = input
+            .orbs
+            .unwrap_or_else(|| self.storage.get_orb_settings().transit);
+        let include_cusps = input.include_house_cusps.unwrap_or(false);
+        let obliquity = match calc_obliquity(julian_day) {
+            Ok(o) => o,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate obliquity: {}", e)
+                })
+                .to_string()
+            }
+        };
+        let natal_declinations = natal_chart
+            .as_ref()
+            .map(|chart| chart.planet_declinations(&extra))
+            .unwrap_or_default();
         let mut transits = Vec::new();
 
-        for (planet, position) in positions {
+        for (body, position) in positions {
             let mut aspects_to_natal = Vec::new();
 
             if let Some(ref chart) = natal_chart {
-                for (natal_planet, natal_pos) in &chart.planets {
-                    if let Some((aspect_type, orb)) =
-                        find_aspect(position.longitude, natal_pos.longitude, false)
-                    {
-                        aspects_to_natal.push(Aspect::new(
-                            natal_planet.to_string(),
+                for (natal_point, natal_longitude) in chart.natal_points(&extra, include_cusps) {
+                    if let Some((aspect_type, orb)) = orbs.find_point_aspect(
+                        body.chart_point(),
+                        position.longitude,
+                        natal_point,
+                        natal_longitude,
+                        false,
+                    ) {
+                        // Natal points are fixed, so only the transiting planet moves
+                        let rate = orb_rate(
+                            position.longitude,
+                            position.speed_longitude,
+                            natal_longitude,
+                            0.0,
                             aspect_type,
-                            (orb * 10.0).round() / 10.0,
-                        ));
+                        );
+                        aspects_to_natal.push(
+                            Aspect::new(
+                                natal_point.to_string(),
+                                aspect_type,
+                                (orb * 10.0).round() / 10.0,
+                            )
+                            .with_orb_rate(rate),
+                        );
                     }
                 }
             }
 
             let zodiac_pos = position.to_zodiac_position();
 
+            let parallels_to_natal = natal_declinations
+                .iter()
+                .filter_map(|&(natal_point, natal_declination)| {
+                    let (aspect, orb) =
+                        orbs.find_parallel(position.declination, natal_declination)?;
+                    Some(ParallelAspect::new(
+                        body.to_string(),
+                        natal_point.to_string(),
+                        aspect,
+                        orb,
+                    ))
+                })
+                .collect();
+
             transits.push(Transit {
-                planet: planet.to_string(),
+                planet: body.to_string(),
                 sign: zodiac_pos.sign,
                 degree: (zodiac_pos.degree * 10.0).round() / 10.0,
                 retrograde: position.is_retrograde,
+                declination: (position.declination * 100.0).round() / 100.0,
+                out_of_bounds: position.is_out_of_bounds(obliquity),
                 aspects_to_natal,
+                parallels_to_natal,
             });
         }
 
         let response = GetDailyTransitsResponse {
             date: input.date,
+            time: julian_day_to_timestamp(julian_day),
+            timezone,
+            zodiac: zodiac.to_string(),
             transits,
         };
 
@@ -325,13 +1029,38 @@ impl StelliumServer {
     fn get_retrograde_status(&self, input: RetrogradeStatusInput) -> String {
         let parsed_date = match NaiveDate::parse_from_str(&input.date, "%Y-%m-%d") {
             Ok(d) => d,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        let timezone = match parse_timezone(input.timezone.as_deref(), None) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
 
-        let julian_day = date_to_julian_day(parsed_date);
+        // The status is taken at local midnight in the timezone
+        let julian_day = match query_julian_day(parsed_date, None, input.timezone.as_deref(), None)
+        {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
         let include_upcoming = input.include_upcoming.unwrap_or(true);
         let days_ahead = input.days_ahead.unwrap_or(90);
 
@@ -343,49 +1072,106 @@ impl StelliumServer {
                 continue;
             }
 
-            let position = match calc_planet_position(*planet, julian_day) {
+            let position = match calc_planet_position(*planet, julian_day, ZodiacMode::Tropical) {
                 Ok(p) => p,
-                Err(e) => return json!({
-                    "success": false,
-                    "error": format!("Failed to calculate position: {}", e)
-                }).to_string(),
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": format!("Failed to calculate position: {}", e)
+                    })
+                    .to_string()
+                }
             };
 
             if position.is_retrograde {
-                let end_date = if let Ok(Some((jd, _))) =
-                    find_next_station(*planet, julian_day, days_ahead as i32)
-                {
-                    Some(julian_day_to_date(jd).format("%Y-%m-%d").to_string())
-                } else {
-                    None
+                let cycle = match find_current_retrograde_cycle(*planet, julian_day) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        return json!({
+                            "success": false,
+                            "error": format!("Failed to calculate retrograde cycle: {}", e)
+                        })
+                        .to_string()
+                    }
+                };
+
+                // Without a station in range, fall back to searching ahead for the end only
+                let (start_jd, end_jd) = match &cycle {
+                    Some(c) => (Some(c.retrograde_station), c.direct_station),
+                    None => (
+                        None,
+                        find_next_station(*planet, julian_day, days_ahead as i32)
+                            .ok()
+                            .flatten()
+                            .map(|(jd, _)| jd),
+                    ),
                 };
+                let pre_shadow_start = cycle.as_ref().and_then(|c| c.pre_shadow_start);
+                let post_shadow_end = cycle.as_ref().and_then(|c| c.post_shadow_end);
+                let end_local = end_jd.and_then(|jd| local_timestamp(jd, timezone.as_ref()));
 
                 currently_retrograde.push(RetrogradeInfo {
                     planet: planet.to_string(),
                     retrograde: true,
-                    retrograde_start: None,
-                    retrograde_end: end_date.clone(),
-                    direct_station: end_date,
+                    retrograde_start: start_jd.map(julian_day_to_timestamp),
+                    retrograde_end: end_jd.map(julian_day_to_timestamp),
+                    direct_station: end_jd.map(julian_day_to_timestamp),
+                    retrograde_start_local: start_jd
+                        .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                    retrograde_end_local: end_local.clone(),
+                    direct_station_local: end_local,
+                    retrograde_station_position: cycle
+                        .as_ref()
+                        .map(|c| display_position(c.retrograde_longitude)),
+                    direct_station_position: cycle
+                        .as_ref()
+                        .and_then(|c| c.direct_longitude)
+                        .map(display_position),
+                    pre_shadow_start: pre_shadow_start.map(julian_day_to_timestamp),
+                    post_shadow_end: post_shadow_end.map(julian_day_to_timestamp),
+                    pre_shadow_start_local: pre_shadow_start
+                        .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                    post_shadow_end_local: post_shadow_end
+                        .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
                 });
             } else if include_upcoming {
                 if let Ok(Some((jd, is_turning_retrograde))) =
                     find_next_station(*planet, julian_day, days_ahead as i32)
                 {
                     if is_turning_retrograde {
+                        let cycle =
+                            match find_retrograde_cycle(*planet, jd) {
+                                Ok(c) => c,
+                                Err(e) => return json!({
+                                    "success": false,
+                                    "error": format!("Failed to calculate retrograde cycle: {}", e)
+                                })
+                                .to_string(),
+                            };
+
                         let days_until = (jd - julian_day).round() as i64;
-                        let end_jd = if let Ok(Some((end, _))) =
-                            find_next_station(*planet, jd + 1.0, 120)
-                        {
-                            end
-                        } else {
-                            jd + 21.0
-                        };
 
                         upcoming_retrogrades.push(UpcomingRetrograde {
                             planet: planet.to_string(),
-                            retrograde_start: julian_day_to_date(jd).format("%Y-%m-%d").to_string(),
-                            retrograde_end: julian_day_to_date(end_jd).format("%Y-%m-%d").to_string(),
+                            retrograde_start: julian_day_to_timestamp(jd),
+                            retrograde_end: cycle.direct_station.map(julian_day_to_timestamp),
+                            retrograde_start_local: local_timestamp(jd, timezone.as_ref()),
+                            retrograde_end_local: cycle
+                                .direct_station
+                                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
                             days_until,
+                            retrograde_station_position: display_position(
+                                cycle.retrograde_longitude,
+                            ),
+                            direct_station_position: cycle.direct_longitude.map(display_position),
+                            pre_shadow_start: cycle.pre_shadow_start.map(julian_day_to_timestamp),
+                            post_shadow_end: cycle.post_shadow_end.map(julian_day_to_timestamp),
+                            pre_shadow_start_local: cycle
+                                .pre_shadow_start
+                                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                            post_shadow_end_local: cycle
+                                .post_shadow_end
+                                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
                         });
                     }
                 }
@@ -394,6 +1180,7 @@ impl StelliumServer {
 
         let response = GetRetrogradeStatusResponse {
             date: input.date,
+            timezone: input.timezone,
             currently_retrograde,
             upcoming_retrogrades,
         };
@@ -404,28 +1191,68 @@ impl StelliumServer {
     fn get_lunar_info(&self, input: LunarInfoInput) -> String {
         let parsed_date = match NaiveDate::parse_from_str(&input.date, "%Y-%m-%d") {
             Ok(d) => d,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        let default_chart = self.storage.get_default_chart();
+        let timezone_name = input
+            .timezone
+            .or_else(|| default_chart.as_ref().map(|c| c.timezone.clone()));
+        let longitude = default_chart.as_ref().map(|c| c.longitude);
+        let timezone = match parse_timezone(timezone_name.as_deref(), longitude) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
 
-        let julian_day = date_to_julian_day(parsed_date);
+        let julian_day = match query_julian_day(
+            parsed_date,
+            input.time.as_deref(),
+            timezone_name.as_deref(),
+            longitude,
+        ) {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa).unwrap_or_default();
 
-        let moon_pos = match calc_planet_position(Planet::Moon, julian_day) {
+        let moon_pos = match calc_planet_position(Planet::Moon, julian_day, zodiac) {
             Ok(p) => p,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Failed to calculate Moon position: {}", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate Moon position: {}", e)
+                })
+                .to_string()
+            }
         };
 
         let phase_angle = match calc_sun_moon_angle(julian_day) {
             Ok(a) => a,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Failed to calculate phase angle: {}", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate phase angle: {}", e)
+                })
+                .to_string()
+            }
         };
 
         let moon_zodiac = moon_pos.to_zodiac_position();
@@ -433,33 +1260,93 @@ impl StelliumServer {
         let lunar_phase = LunarPhase {
             phase_name: LunarPhaseName::from_phase_angle(phase_angle),
             phase_percent: ((phase_angle / 360.0) * 100.0).round() as u8,
-            illumination: (LunarPhaseName::illumination_from_angle(phase_angle) * 100.0).round() / 100.0,
+            illumination: (LunarPhaseName::illumination_from_angle(phase_angle) * 100.0).round()
+                / 100.0,
             moon_sign: moon_zodiac.sign,
             moon_degree: (moon_zodiac.degree * 10.0).round() / 10.0,
         };
 
-        let prev_new = find_previous_lunar_phase(julian_day, 0.0, 30).unwrap_or(julian_day - 14.0);
-        let prev_full = find_previous_lunar_phase(julian_day, 180.0, 30).unwrap_or(julian_day - 7.0);
-        let next_new = find_next_new_moon(julian_day, 30).ok().flatten().unwrap_or(julian_day + 29.5);
-        let next_full = find_next_full_moon(julian_day, 30).ok().flatten().unwrap_or(julian_day + 14.0);
+        let prev_new = find_previous_new_moon(julian_day, 30)
+            .ok()
+            .flatten()
+            .unwrap_or(julian_day - 14.0);
+        let prev_full = find_previous_full_moon(julian_day, 30)
+            .ok()
+            .flatten()
+            .unwrap_or(julian_day - 7.0);
+        let next_new = find_next_new_moon(julian_day, 30)
+            .ok()
+            .flatten()
+            .unwrap_or(julian_day + 29.5);
+        let next_full = find_next_full_moon(julian_day, 30)
+            .ok()
+            .flatten()
+            .unwrap_or(julian_day + 14.0);
 
         let lunar_cycle = LunarCycle {
-            new_moon: julian_day_to_date(prev_new).format("%Y-%m-%d").to_string(),
-            full_moon: julian_day_to_date(prev_full).format("%Y-%m-%d").to_string(),
-            next_new_moon: julian_day_to_date(next_new).format("%Y-%m-%d").to_string(),
-            next_full_moon: julian_day_to_date(next_full).format("%Y-%m-%d").to_string(),
+            new_moon: julian_day_to_timestamp(prev_new),
+            full_moon: julian_day_to_timestamp(prev_full),
+            next_new_moon: julian_day_to_timestamp(next_new),
+            next_full_moon: julian_day_to_timestamp(next_full),
+            new_moon_local: local_timestamp(prev_new, timezone.as_ref()),
+            full_moon_local: local_timestamp(prev_full, timezone.as_ref()),
+            next_new_moon_local: local_timestamp(next_new, timezone.as_ref()),
+            next_full_moon_local: local_timestamp(next_full, timezone.as_ref()),
+            next_solar_eclipse: find_solar_eclipse(julian_day, false)
+                .and_then(|e| eclipse_summary(&e, zodiac, timezone.as_ref()))
+                .ok(),
+            next_lunar_eclipse: find_lunar_eclipse(julian_day, false)
+                .and_then(|e| eclipse_summary(&e, zodiac, timezone.as_ref()))
+                .ok(),
         };
 
-        let void_of_course = VoidOfCourse {
-            is_void: false,
-            last_aspect_time: None,
-            next_aspect_time: None,
-            enters_void_at: None,
-            exits_void_at: None,
-        };
+        let rule = input.void_of_course_rule.unwrap_or_default();
+        let void_period = match find_void_of_course(julian_day, rule.planets(), zodiac) {
+            Ok(p) => p,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate void-of-course Moon: {}", e)
+                })
+                .to_string()
+            }
+        };
+        let is_void = julian_day >= void_period.start;
+
+        // While void, the next aspect is made after the Moon changes sign
+        let next_aspect = if is_void {
+            find_next_moon_aspect(void_period.end, rule.planets())
+        } else {
+            find_next_moon_aspect(julian_day, rule.planets())
+        };
+        let last_aspect_jd = void_period.last_aspect.as_ref().map(|a| a.julian_day);
+        let next_aspect_jd = next_aspect.ok().flatten().map(|a| a.julian_day);
+
+        let void_of_course = VoidOfCourse {
+            is_void,
+            rule,
+            last_aspect: void_period
+                .last_aspect
+                .as_ref()
+                .map(|a| format!("Moon {} {}", a.aspect_type, a.planet)),
+            last_aspect_time: last_aspect_jd.map(julian_day_to_timestamp),
+            next_aspect_time: next_aspect_jd.map(julian_day_to_timestamp),
+            enters_void_at: Some(julian_day_to_timestamp(void_period.start)),
+            exits_void_at: Some(julian_day_to_timestamp(void_period.end)),
+            last_aspect_time_local: last_aspect_jd
+                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+            next_aspect_time_local: next_aspect_jd
+                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+            enters_void_at_local: local_timestamp(void_period.start, timezone.as_ref()),
+            exits_void_at_local: local_timestamp(void_period.end, timezone.as_ref()),
+            next_sign: Some(void_period.next_sign),
+        };
 
         let response = GetLunarInfoResponse {
             date: input.date,
+            time: julian_day_to_timestamp(julian_day),
+            timezone: timezone_name,
+            zodiac: zodiac.to_string(),
             lunar_phase,
             void_of_course,
             lunar_cycle,
@@ -469,111 +1356,325 @@ impl StelliumServer {
     }
 
     fn get_transit_report(&self, input: TransitReportInput) -> String {
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         let parsed_start = match NaiveDate::parse_from_str(&input.start_date, "%Y-%m-%d") {
             Ok(d) => d,
-            Err(e) => return json!({
-                "success": false,
-                "error": format!("Invalid start_date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid start_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
         };
 
         let parsed_end = match NaiveDate::parse_from_str(&input.end_date, "%Y-%m-%d") {
             Ok(d) => d,
-            Err(e) => return json!({
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid end_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        if parsed_end < parsed_start {
+            return json!({
                 "success": false,
-                "error": format!("Invalid end_date format: {}. Expected YYYY-MM-DD", e)
-            }).to_string(),
+                "error": "end_date must not be before start_date"
+            })
+            .to_string();
+        }
+        if (parsed_end - parsed_start).num_days() > MAX_TRANSIT_REPORT_DAYS {
+            return json!({
+                "success": false,
+                "error": format!("The report covers at most {} days per call", MAX_TRANSIT_REPORT_DAYS)
+            }).to_string();
+        }
+
+        let timezone = match parse_timezone(input.timezone.as_deref(), None) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
 
         let include_minor = input.include_minor_aspects.unwrap_or(false);
         let natal_chart = self.storage.get_default_chart();
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .or_else(|| natal_chart.as_ref().map(|c| c.zodiac_mode()))
+            .unwrap_or_default();
+
+        let natal_chart = match natal_chart.map(|c| chart_in_zodiac(c, zodiac)).transpose() {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
 
-        let start_jd = date_to_julian_day(parsed_start);
-        let end_jd = date_to_julian_day(parsed_end);
-        let days = (end_jd - start_jd).ceil() as i32;
+        // From the start of the first local day to the end of the last
+        let day_bounds = query_julian_day(parsed_start, None, input.timezone.as_deref(), None)
+            .and_then(|start| {
+                let end = query_julian_day(
+                    parsed_end + Days::new(1),
+                    None,
+                    input.timezone.as_deref(),
+                    None,
+                )?;
+                Ok((start, end))
+            });
+        let (start_jd, end_jd) = match day_bounds {
+            Ok(bounds) => bounds,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
 
         let mut major_events = Vec::new();
         let mut lunar_events = Vec::new();
         let mut retrograde_events = Vec::new();
 
         for planet in Planet::all() {
-            if let Ok(Some((jd, sign))) = find_next_sign_ingress(*planet, start_jd, days) {
-                if jd <= end_jd {
-                    let event_desc = if *planet == Planet::Sun {
-                        let special = match sign {
-                            crate::models::ZodiacSign::Aries => Some("Spring Equinox"),
-                            crate::models::ZodiacSign::Cancer => Some("Summer Solstice"),
-                            crate::models::ZodiacSign::Libra => Some("Fall Equinox"),
-                            crate::models::ZodiacSign::Capricorn => Some("Winter Solstice"),
-                            _ => None,
-                        };
-                        if let Some(special_name) = special {
-                            format!("{} enters {} ({})", planet, sign, special_name)
-                        } else {
-                            format!("{} enters {}", planet, sign)
-                        }
+            let ingresses = match find_sign_ingresses(*planet, start_jd, end_jd, zodiac) {
+                Ok(i) => i,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            };
+            for (jd, sign, is_retrograde) in ingresses {
+                let event_desc = if *planet == Planet::Sun {
+                    if let Some(special_name) = zodiac.season_start(sign) {
+                        format!("{} enters {} ({})", planet, sign, special_name)
                     } else {
                         format!("{} enters {}", planet, sign)
+                    }
+                } else if is_retrograde && !planet.is_lunar_node() {
+                    // Retrograde motion carries the planet back into the sign it just left
+                    format!("{} re-enters {} (retrograde)", planet, sign)
+                } else {
+                    format!("{} enters {}", planet, sign)
+                };
+
+                major_events.push(MajorEvent {
+                    date: julian_day_to_date(jd).format("%Y-%m-%d").to_string(),
+                    event: event_desc,
+                    event_type: "sign_change".to_string(),
+                    time: Some(julian_day_to_timestamp(jd)),
+                    local_time: local_timestamp(jd, timezone.as_ref()),
+                    orb: None,
+                    affected_planets: vec![planet.to_string()],
+                    window: None,
+                    house: None,
+                    life_area: None,
+                });
+            }
+
+            if planet.can_retrograde() {
+                let stations = match find_stations(*planet, start_jd, end_jd) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        return json!({
+                            "success": false,
+                            "error": e
+                        })
+                        .to_string()
+                    }
+                };
+                for (jd, is_retrograde) in stations {
+                    let event_desc = if is_retrograde {
+                        format!("{} stations retrograde", planet)
+                    } else {
+                        format!("{} stations direct", planet)
                     };
 
-                    major_events.push(MajorEvent {
+                    retrograde_events.push(MajorEvent {
                         date: julian_day_to_date(jd).format("%Y-%m-%d").to_string(),
                         event: event_desc,
-                        event_type: "sign_change".to_string(),
+                        event_type: "station".to_string(),
+                        time: Some(julian_day_to_timestamp(jd)),
+                        local_time: local_timestamp(jd, timezone.as_ref()),
                         orb: None,
                         affected_planets: vec![planet.to_string()],
+                        window: None,
+                        house: None,
+                        life_area: None,
                     });
                 }
             }
+        }
+
+        if let Some(ref chart) = natal_chart {
+            let orbs = input
+                .orbs
+                .clone()
+                .unwrap_or_else(|| self.storage.get_orb_settings().transit);
+            let aspects = orbs.enabled_aspects(include_minor);
+            let extra = input.points.clone().unwrap_or_default();
+            let natal_points =
+                chart.natal_points(&extra, input.include_house_cusps.unwrap_or(false));
+            let mut bodies = transiting_bodies(&extra);
+            // The Moon's aspects last hours and are otherwise left to the daily tools
+            if !input.include_moon.unwrap_or(false) {
+                bodies.retain(|p| *p != Planet::Moon);
+            }
 
-            if planet.can_retrograde() {
-                if let Ok(Some((jd, is_retrograde))) = find_next_station(*planet, start_jd, days) {
-                    if jd <= end_jd {
-                        let event_desc = if is_retrograde {
-                            format!("{} stations retrograde", planet)
+            let natal_declinations = chart.planet_declinations(&extra);
+
+            for transit_planet in &bodies {
+                let windows = find_transit_aspect_windows(
+                    *transit_planet,
+                    &natal_points,
+                    &aspects,
+                    &orbs,
+                    start_jd,
+                    end_jd,
+                    zodiac,
+                )
+                .and_then(|mut windows| {
+                    let parallels = find_transit_parallel_windows(
+                        *transit_planet,
+                        &natal_declinations,
+                        &orbs,
+                        start_jd,
+                        end_jd,
+                    )?;
+                    windows.extend(parallels);
+                    Ok(windows)
+                });
+                let windows = match windows {
+                    Ok(w) => w,
+                    Err(e) => {
+                        return json!({
+                            "success": false,
+                            "error": e
+                        })
+                        .to_string()
+                    }
+                };
+
+                for window in windows {
+                    let event_jd = window
+                        .exact
+                        .first()
+                        .copied()
+                        .or(window.enters_orb)
+                        .unwrap_or(start_jd);
+                    let exact_jd = window.exact.first().copied();
+
+                    major_events.push(MajorEvent {
+                        date: julian_day_to_date(event_jd).format("%Y-%m-%d").to_string(),
+                        event: format!(
+                            "{} {} natal {}",
+                            window.transit_planet, window.aspect, window.natal_point
+                        ),
+                        event_type: match window.aspect {
+                            TransitAspect::Longitude(_) => "aspect",
+                            TransitAspect::Declination(_) => "parallel",
+                        }
+                        .to_string(),
+                        time: exact_jd.map(julian_day_to_timestamp),
+                        local_time: exact_jd.and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                        orb: Some((window.closest_orb * 10.0).round() / 10.0),
+                        affected_planets: vec![
+                            window.transit_planet.to_string(),
+                            window.natal_point.to_string(),
+                        ],
+                        window: Some(AspectWindow {
+                            enters_orb: window.enters_orb.map(julian_day_to_timestamp),
+                            leaves_orb: window.leaves_orb.map(julian_day_to_timestamp),
+                            exact: window
+                                .exact
+                                .iter()
+                                .map(|jd| julian_day_to_timestamp(*jd))
+                                .collect(),
+                            enters_orb_local: window
+                                .enters_orb
+                                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                            leaves_orb_local: window
+                                .leaves_orb
+                                .and_then(|jd| local_timestamp(jd, timezone.as_ref())),
+                            exact_local: window
+                                .exact
+                                .iter()
+                                .filter_map(|jd| local_timestamp(*jd, timezone.as_ref()))
+                                .collect(),
+                        }),
+                        house: None,
+                        life_area: None,
+                    });
+                }
+            }
+
+            // Transiting planets moving into another natal house
+            if let Some(cusps) = chart.cusp_longitudes() {
+                for planet in &bodies {
+                    let ingresses =
+                        match find_house_ingresses(*planet, &cusps, start_jd, end_jd, zodiac) {
+                            Ok(i) => i,
+                            Err(e) => {
+                                return json!({
+                                    "success": false,
+                                    "error": e
+                                })
+                                .to_string()
+                            }
+                        };
+                    for (jd, house, is_retrograde) in ingresses {
+                        let life_area = LifeArea::from_house(house);
+                        let area = life_area.map(|a| format!(" ({})", a)).unwrap_or_default();
+                        let event_desc = if is_retrograde && !planet.is_lunar_node() {
+                            // Retrograde motion carries the planet back over the cusp it just crossed
+                            format!(
+                                "{} re-enters natal {} house{} (retrograde)",
+                                planet,
+                                ordinal(house),
+                                area
+                            )
                         } else {
-                            format!("{} stations direct", planet)
+                            format!("{} enters natal {} house{}", planet, ordinal(house), area)
                         };
 
-                        retrograde_events.push(MajorEvent {
+                        major_events.push(MajorEvent {
                             date: julian_day_to_date(jd).format("%Y-%m-%d").to_string(),
                             event: event_desc,
-                            event_type: "station".to_string(),
+                            event_type: "house_ingress".to_string(),
+                            time: Some(julian_day_to_timestamp(jd)),
+                            local_time: local_timestamp(jd, timezone.as_ref()),
                             orb: None,
                             affected_planets: vec![planet.to_string()],
+                            window: None,
+                            house: Some(house),
+                            life_area,
                         });
                     }
                 }
             }
         }
 
-        if let Some(ref chart) = natal_chart {
-            let mut jd = start_jd;
-            while jd <= end_jd {
-                let positions = calc_all_planets(jd).unwrap_or_default();
-
-                for (transit_planet, transit_pos) in &positions {
-                    for (natal_planet, natal_pos) in &chart.planets {
-                        if let Some((aspect_type, orb)) =
-                            find_aspect(transit_pos.longitude, natal_pos.longitude, include_minor)
-                        {
-                            if orb < 0.5 {
-                                major_events.push(MajorEvent {
-                                    date: julian_day_to_date(jd).format("%Y-%m-%d").to_string(),
-                                    event: format!("{} {} natal {}", transit_planet, aspect_type, natal_planet),
-                                    event_type: "aspect".to_string(),
-                                    orb: Some((orb * 10.0).round() / 10.0),
-                                    affected_planets: vec![transit_planet.to_string(), natal_planet.to_string()],
-                                });
-                            }
-                        }
-                    }
-                }
-
-                jd += 1.0;
-            }
-        }
-
         let mut jd = start_jd;
         while jd <= end_jd {
             if let Ok(Some(new_jd)) = find_next_new_moon(jd, (end_jd - jd + 1.0) as i32) {
@@ -582,6 +1683,10 @@ impl StelliumServer {
                         date: julian_day_to_date(new_jd).format("%Y-%m-%d").to_string(),
                         event: "New Moon".to_string(),
                         event_type: "lunar_phase".to_string(),
+                        time: julian_day_to_timestamp(new_jd),
+                        end_time: None,
+                        local_time: local_timestamp(new_jd, timezone.as_ref()),
+                        end_local_time: None,
                     });
                     jd = new_jd + 1.0;
                 } else {
@@ -600,6 +1705,10 @@ impl StelliumServer {
                         date: julian_day_to_date(full_jd).format("%Y-%m-%d").to_string(),
                         event: "Full Moon".to_string(),
                         event_type: "lunar_phase".to_string(),
+                        time: julian_day_to_timestamp(full_jd),
+                        end_time: None,
+                        local_time: local_timestamp(full_jd, timezone.as_ref()),
+                        end_local_time: None,
                     });
                     jd = full_jd + 1.0;
                 } else {
@@ -610,15 +1719,89 @@ impl StelliumServer {
             }
         }
 
-        major_events.sort_by(|a, b| a.date.cmp(&b.date));
-        lunar_events.sort_by(|a, b| a.date.cmp(&b.date));
-        retrograde_events.sort_by(|a, b| a.date.cmp(&b.date));
+        let eclipses = find_eclipses(start_jd, end_jd, None).and_then(|eclipses| {
+            eclipses
+                .into_iter()
+                .map(|eclipse| {
+                    Ok((
+                        eclipse_summary(&eclipse, zodiac, timezone.as_ref())?,
+                        eclipse,
+                    ))
+                })
+                .collect::<Result<Vec<_>, String>>()
+        });
+        let eclipses = match eclipses {
+            Ok(e) => e,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+        for (summary, eclipse) in eclipses {
+            lunar_events.push(LunarEvent {
+                date: julian_day_to_date(eclipse.maximum)
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                event: format!(
+                    "{} at {}",
+                    summary.title(),
+                    summary.position.format_degree_sign()
+                ),
+                event_type: format!("{}_eclipse", eclipse.kind.to_string().to_lowercase()),
+                time: summary.maximum,
+                end_time: None,
+                local_time: summary.maximum_local,
+                end_local_time: None,
+            });
+        }
+
+        let voc_rule = input.void_of_course_rule.unwrap_or_default();
+        let periods = match find_void_periods(start_jd, end_jd, voc_rule.planets(), zodiac) {
+            Ok(p) => p,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+        for period in periods {
+            let event = match &period.last_aspect {
+                Some(aspect) => format!(
+                    "Moon void of course after {} {}, until it enters {}",
+                    aspect.aspect_type, aspect.planet, period.next_sign
+                ),
+                None => format!("Moon void of course until it enters {}", period.next_sign),
+            };
+
+            lunar_events.push(LunarEvent {
+                date: julian_day_to_date(period.start)
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                event,
+                event_type: "void_of_course".to_string(),
+                time: julian_day_to_timestamp(period.start),
+                end_time: Some(julian_day_to_timestamp(period.end)),
+                local_time: local_timestamp(period.start, timezone.as_ref()),
+                end_local_time: local_timestamp(period.end, timezone.as_ref()),
+            });
+        }
+
+        major_events.sort_by(|a, b| (&a.date, &a.time).cmp(&(&b.date, &b.time)));
+        lunar_events.sort_by(|a, b| a.time.cmp(&b.time));
+        retrograde_events.sort_by(|a, b| (&a.date, &a.time).cmp(&(&b.date, &b.time)));
 
         let response = GetTransitReportResponse {
             period: DateRange {
                 start_date: input.start_date,
                 end_date: input.end_date,
             },
+            timezone: input.timezone,
+            zodiac: zodiac.to_string(),
             major_events,
             lunar_events,
             retrograde_events,
@@ -627,6 +1810,120 @@ impl StelliumServer {
         serde_json::to_string_pretty(&response).unwrap()
     }
 
+    fn get_void_of_course_calendar(&self, input: VoidOfCourseCalendarInput) -> String {
+        let parsed_start = match NaiveDate::parse_from_str(&input.start_date, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid start_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        let parsed_end = match NaiveDate::parse_from_str(&input.end_date, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid end_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        if parsed_end < parsed_start {
+            return json!({
+                "success": false,
+                "error": "end_date must not be before start_date"
+            })
+            .to_string();
+        }
+        if (parsed_end - parsed_start).num_days() > MAX_VOID_CALENDAR_DAYS {
+            return json!({
+                "success": false,
+                "error": format!("The calendar covers at most {} days per call", MAX_VOID_CALENDAR_DAYS)
+            }).to_string();
+        }
+
+        let timezone = match parse_timezone(input.timezone.as_deref(), None) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let rule = input.void_of_course_rule.unwrap_or_default();
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa).unwrap_or_default();
+
+        // From the start of the first local day to the end of the last
+        let day_bounds = query_julian_day(parsed_start, None, input.timezone.as_deref(), None)
+            .and_then(|start| {
+                let end = query_julian_day(
+                    parsed_end + Days::new(1),
+                    None,
+                    input.timezone.as_deref(),
+                    None,
+                )?;
+                Ok((start, end))
+            });
+        let (start_jd, end_jd) = match day_bounds {
+            Ok(bounds) => bounds,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let periods = match find_void_periods(start_jd, end_jd, rule.planets(), zodiac) {
+            Ok(p) => p,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate void-of-course periods: {}", e)
+                })
+                .to_string()
+            }
+        };
+
+        let void_periods = periods
+            .iter()
+            .map(|period| VoidOfCoursePeriod {
+                start: julian_day_to_timestamp(period.start),
+                end: julian_day_to_timestamp(period.end),
+                start_local: local_timestamp(period.start, timezone.as_ref()),
+                end_local: local_timestamp(period.end, timezone.as_ref()),
+                duration_hours: ((period.end - period.start) * 24.0 * 100.0).round() / 100.0,
+                last_aspect: period
+                    .last_aspect
+                    .as_ref()
+                    .map(|a| format!("Moon {} {}", a.aspect_type, a.planet)),
+                next_sign: period.next_sign,
+            })
+            .collect();
+
+        let response = GetVoidOfCourseCalendarResponse {
+            period: DateRange {
+                start_date: input.start_date,
+                end_date: input.end_date,
+            },
+            timezone: input.timezone,
+            rule,
+            zodiac: zodiac.to_string(),
+            void_periods,
+        };
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
     fn list_natal_charts(&self) -> String {
         let charts = self.storage.list_charts();
 
@@ -651,22 +1948,134 @@ impl StelliumServer {
     }
 
     fn get_natal_chart(&self, input: GetNatalChartInput) -> String {
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         let chart = match self.storage.get_chart(&input.name) {
             Some(c) => c,
-            None => return json!({
-                "success": false,
-                "error": format!("Natal chart '{}' not found", input.name)
-            }).to_string(),
+            None => {
+                return json!({
+                    "success": false,
+                    "error": format!("Natal chart '{}' not found", input.name)
+                })
+                .to_string()
+            }
+        };
+
+        // Re-render in another house system on request (the stored chart is unchanged)
+        let chart = match input.house_system {
+            Some(system) if system != chart.requested_house_system() => {
+                match recalculate_chart(&chart, chart.zodiac_mode(), system) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        return json!({
+                            "success": false,
+                            "error": e
+                        })
+                        .to_string()
+                    }
+                }
+            }
+            _ => chart,
+        };
+
+        // The grid follows the current orb policy rather than the one the chart was stored with
+        // (with natal speeds, so applying and separating can be told apart)
+        let zodiac = chart.zodiac_mode();
+        let mut chart = match chart_in_zodiac(chart, zodiac) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
+        let extra = input.points.unwrap_or_default();
+        let orbs = input
+            .orbs
+            .unwrap_or_else(|| self.storage.get_orb_settings().natal);
+        chart.aspects = chart.calculate_aspects(&orbs, &extra);
+
+        // Further bodies (asteroids) are calculated at the birth moment on request
+        let mut bodies = Vec::new();
+        if let Some(requested) = input.bodies.filter(|b| !b.is_empty()) {
+            let julian_day = match birth_julian_day(&chart) {
+                Ok(jd) => jd,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            };
+            let natal_points = chart.natal_points(&extra, false);
+
+            for body in requested {
+                let position = match calc_body_position(body, julian_day, zodiac) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        return json!({
+                            "success": false,
+                            "error": e
+                        })
+                        .to_string()
+                    }
+                };
+                let aspects: Vec<Value> = natal_points
+                    .iter()
+                    .filter(|(point, _)| *point != body.chart_point())
+                    .filter_map(|&(point, longitude)| {
+                        let (aspect, orb) = orbs.find_point_aspect(
+                            body.chart_point(),
+                            position.longitude,
+                            point,
+                            longitude,
+                            false,
+                        )?;
+                        Some(json!({
+                            "point": point.to_string(),
+                            "aspect": aspect,
+                            "orb": (orb * 100.0).round() / 100.0
+                        }))
+                    })
+                    .collect();
+
+                bodies.push(json!({
+                    "body": body.to_string(),
+                    "position": position.to_zodiac_position().format_degree_sign(),
+                    "house": chart.cusp_longitudes().map(|cusps| planet_in_house(position.longitude, &cusps)),
+                    "retrograde": position.is_retrograde,
+                    "declination": (position.declination * 100.0).round() / 100.0,
+                    "aspects": aspects
+                }));
+            }
+        }
 
         let summary = NatalChartSummary::from(&chart);
-        let response = json!({
+        let mut response = json!({
             "name": chart.name,
             "birth_date": chart.birth_date,
             "birth_time": chart.birth_time,
             "birth_location": chart.birth_location,
-            "positions": summary
+            "timezone": chart.timezone,
+            "utc_offset": chart.utc_offset,
+            "positions": summary,
+            "aspect_grid": aspect_grid(&chart.aspects)
         });
+        if orbs.parallels {
+            response["parallels"] = json!(chart.calculate_parallels(&orbs, &extra));
+        }
+        if !bodies.is_empty() {
+            response["bodies"] = Value::Array(bodies);
+        }
 
         serde_json::to_string_pretty(&response).unwrap()
     }
@@ -689,7 +2098,8 @@ impl StelliumServer {
                 return json!({
                     "success": false,
                     "error": format!("Natal chart '{}' not found", input.name)
-                }).to_string();
+                })
+                .to_string();
             }
         };
 
@@ -711,71 +2121,123 @@ impl StelliumServer {
     }
 
     fn get_compatibility(&self, input: GetCompatibilityInput) -> String {
-        // Load both charts
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
+        // Load both charts
         let chart1 = match self.storage.get_chart(&input.person1_name) {
             Some(c) => c,
-            None => return json!({
-                "success": false,
-                "error": format!("Natal chart '{}' not found", input.person1_name)
-            }).to_string(),
+            None => {
+                return json!({
+                    "success": false,
+                    "error": format!("Natal chart '{}' not found", input.person1_name)
+                })
+                .to_string()
+            }
         };
 
         let chart2 = match self.storage.get_chart(&input.person2_name) {
             Some(c) => c,
-            None => return json!({
-                "success": false,
-                "error": format!("Natal chart '{}' not found", input.person2_name)
-            }).to_string(),
+            None => {
+                return json!({
+                    "success": false,
+                    "error": format!("Natal chart '{}' not found", input.person2_name)
+                })
+                .to_string()
+            }
+        };
+
+        // Compare both charts in a single zodiac
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .unwrap_or_else(|| chart1.zodiac_mode());
+        let (chart1, chart2) = match (
+            chart_in_zodiac(chart1, zodiac),
+            chart_in_zodiac(chart2, zodiac),
+        ) {
+            (Ok(c1), Ok(c2)) => (c1, c2),
+            (Err(e), _) | (_, Err(e)) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
         };
 
         let include_minor = input.include_minor_aspects.unwrap_or(false);
+        let orbs = input
+            .orbs
+            .unwrap_or_else(|| self.storage.get_orb_settings().synastry);
         let mut synastry_aspects = Vec::new();
         let mut exact_aspects = Vec::new();
 
-        // Compare every planet in chart1 against every planet in chart2
-        for planet1 in Planet::all() {
-            let pos1 = match chart1.planets.get(planet1) {
-                Some(p) => p,
-                None => continue,
-            };
-
-            let house1 = chart1.planet_positions.get(planet1).map(|p| p.house);
-
-            for planet2 in Planet::all() {
-                let pos2 = match chart2.planets.get(planet2) {
-                    Some(p) => p,
-                    None => continue,
-                };
-
-                let house2 = chart2.planet_positions.get(planet2).map(|p| p.house);
+        // Compare every planet and angle in chart1 against every planet and angle in chart2
+        // (angle-to-angle and cusp contacts are left out)
+        let include_cusps = input.include_house_cusps.unwrap_or(false);
+        let extra = input.points.unwrap_or_default();
+        let points2 = chart2.natal_points(&extra, include_cusps);
+        for (point1, longitude1) in chart1.natal_points(&extra, include_cusps) {
+            let pos1 = ZodiacPosition::from_longitude(longitude1);
+            let house1 = point1
+                .planet()
+                .and_then(|p| chart1.planet_positions.get(&p))
+                .map(|p| p.house);
+
+            for &(point2, longitude2) in &points2 {
+                if point1.planet().is_none() && point2.planet().is_none() {
+                    continue;
+                }
+                let pos2 = ZodiacPosition::from_longitude(longitude2);
+                let house2 = point2
+                    .planet()
+                    .and_then(|p| chart2.planet_positions.get(&p))
+                    .map(|p| p.house);
 
                 // Check for aspects
-                if let Some((aspect_type, orb)) = find_aspect(pos1.longitude, pos2.longitude, include_minor) {
+                if let Some((aspect_type, orb)) =
+                    orbs.find_point_aspect(point1, longitude1, point2, longitude2, include_minor)
+                {
                     let is_exact = orb < 1.0;
+                    let rate = natal_orb_rate(
+                        &chart1,
+                        (point1, longitude1),
+                        &chart2,
+                        (point2, longitude2),
+                        aspect_type,
+                    );
 
-                    let aspect_info = json!({
-                        "person1_planet": planet1.to_string(),
-                        "person1_position": pos1.format_degree_sign(),
-                        "person1_house": house1,
-                        "person2_planet": planet2.to_string(),
-                        "person2_position": pos2.format_degree_sign(),
-                        "person2_house": house2,
-                        "aspect": aspect_type.to_string(),
-                        "orb": (orb * 100.0).round() / 100.0,
-                        "is_exact": is_exact,
-                        "is_major": aspect_type.is_major()
-                    });
+                    let aspect_info = with_motion(
+                        json!({
+                            "person1_planet": point1.to_string(),
+                            "person1_position": pos1.format_degree_sign(),
+                            "person1_house": house1,
+                            "person2_planet": point2.to_string(),
+                            "person2_position": pos2.format_degree_sign(),
+                            "person2_house": house2,
+                            "aspect": aspect_type.to_string(),
+                            "orb": (orb * 100.0).round() / 100.0,
+                            "is_exact": is_exact,
+                            "is_major": aspect_type.is_major(),
+                            "family": aspect_type.family()
+                        }),
+                        rate,
+                    );
 
                     if is_exact {
                         exact_aspects.push(json!({
                             "aspect": format!("{} {} {} ({})",
-                                input.person1_name, planet1,
+                                input.person1_name, point1,
                                 aspect_type,
-                                planet2),
+                                point2),
                             "description": format!("{}'s {} {} {}'s {} (orb: {:.2}°)",
-                                input.person1_name, planet1,
+                                input.person1_name, point1,
                                 aspect_type,
-                                input.person2_name, planet2,
+                                input.person2_name, point2,
                                 orb)
                         }));
                     }
@@ -785,6 +2247,22 @@ impl StelliumServer {
             }
         }
 
+        // Parallels and contra-parallels by declination, when the policy looks for them
+        let mut parallels = Vec::new();
+        let declinations2 = chart2.planet_declinations(&extra);
+        for (point1, declination1) in chart1.planet_declinations(&extra) {
+            for &(point2, declination2) in &declinations2 {
+                if let Some((aspect, orb)) = orbs.find_parallel(declination1, declination2) {
+                    parallels.push(ParallelAspect::new(
+                        point1.to_string(),
+                        point2.to_string(),
+                        aspect,
+                        orb,
+                    ));
+                }
+            }
+        }
+
         // Build person summaries
         let person1_summary = json!({
             "name": chart1.name,
@@ -824,8 +2302,9 @@ impl StelliumServer {
             }
         }
 
-        let response = json!({
+        let mut response = json!({
             "success": true,
+            "zodiac": zodiac.to_string(),
             "person1": person1_summary,
             "person2": person2_summary,
             "aspects": synastry_aspects,
@@ -845,10 +2324,22 @@ impl StelliumServer {
             }
         });
 
+        if orbs.parallels {
+            response["parallels"] = json!(parallels);
+        }
+
         serde_json::to_string_pretty(&response).unwrap()
     }
 
     fn get_full_chart_analysis(&self, input: GetFullChartAnalysisInput) -> String {
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         // Get the natal chart
         let chart = match self.storage.get_chart(&input.name) {
             Some(c) => c,
@@ -861,10 +2352,36 @@ impl StelliumServer {
             }
         };
 
-        // Parse date (default to today)
-        let date_str = input.date.unwrap_or_else(|| {
-            chrono::Local::now().format("%Y-%m-%d").to_string()
-        });
+        // Charts stored before the additional points and declinations were recorded are recalculated
+        let zodiac = chart.zodiac_mode();
+        let chart = match chart_in_zodiac(chart, zodiac) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let timezone = input.timezone.unwrap_or_else(|| chart.timezone.clone());
+        let longitude = Some(chart.longitude);
+
+        // Parse date (default to today in the query timezone)
+        let date_str = match input.date {
+            Some(d) => d,
+            None => match today_in(&timezone, longitude) {
+                Ok(d) => d,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            },
+        };
         let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
             Ok(d) => d,
             Err(e) => {
@@ -876,10 +2393,25 @@ impl StelliumServer {
             }
         };
 
-        let julian_day = date_to_julian_day(parsed_date);
+        let julian_day = match query_julian_day(
+            parsed_date,
+            input.time.as_deref(),
+            Some(&timezone),
+            longitude,
+        ) {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
 
-        // Calculate current planetary positions
-        let positions = match calc_all_planets(julian_day) {
+        // Calculate current planetary positions in the chart's zodiac
+        let extra = input.points.unwrap_or_default();
+        let positions = match calc_planets(&transiting_bodies(&extra), julian_day, zodiac) {
             Ok(p) => p,
             Err(e) => {
                 return json!({
@@ -890,30 +2422,68 @@ impl StelliumServer {
             }
         };
 
-        // Build daily transits with aspects
+        let orbs = input
+            .orbs
+            .unwrap_or_else(|| self.storage.get_orb_settings().transit);
+
+        // Build daily transits with aspects to natal planets and angles, and parallels when enabled
+        let natal_points = chart.natal_points(&extra, input.include_house_cusps.unwrap_or(false));
+        let natal_declinations = chart.planet_declinations(&extra);
         let mut transits = Vec::new();
         for (planet, position) in &positions {
             let mut aspects_to_natal = Vec::new();
-            for (natal_planet, natal_pos) in &chart.planets {
-                if let Some((aspect_type, orb)) =
-                    find_aspect(position.longitude, natal_pos.longitude, false)
-                {
-                    aspects_to_natal.push(json!({
-                        "natal_planet": natal_planet.to_string(),
-                        "aspect": aspect_type.to_string(),
-                        "orb": (orb * 10.0).round() / 10.0
-                    }));
+            for &(natal_point, natal_longitude) in &natal_points {
+                if let Some((aspect_type, orb)) = orbs.find_point_aspect(
+                    ChartPoint::Planet(*planet),
+                    position.longitude,
+                    natal_point,
+                    natal_longitude,
+                    false,
+                ) {
+                    let rate = orb_rate(
+                        position.longitude,
+                        position.speed_longitude,
+                        natal_longitude,
+                        0.0,
+                        aspect_type,
+                    );
+                    aspects_to_natal.push(with_motion(
+                        json!({
+                            "natal_planet": natal_point.to_string(),
+                            "aspect": aspect_type.to_string(),
+                            "orb": (orb * 10.0).round() / 10.0
+                        }),
+                        Some(rate),
+                    ));
                 }
             }
 
+            let parallels_to_natal: Vec<ParallelAspect> = natal_declinations
+                .iter()
+                .filter_map(|&(natal_point, natal_declination)| {
+                    let (aspect, orb) =
+                        orbs.find_parallel(position.declination, natal_declination)?;
+                    Some(ParallelAspect::new(
+                        planet.to_string(),
+                        natal_point.to_string(),
+                        aspect,
+                        orb,
+                    ))
+                })
+                .collect();
+
             let zodiac_pos = position.to_zodiac_position();
-            transits.push(json!({
+            let mut transit = json!({
                 "planet": planet.to_string(),
                 "sign": zodiac_pos.sign,
                 "degree": (zodiac_pos.degree * 10.0).round() / 10.0,
                 "retrograde": position.is_retrograde,
                 "aspects_to_natal": aspects_to_natal
-            }));
+            });
+            if !parallels_to_natal.is_empty() {
+                transit["parallels_to_natal"] = json!(parallels_to_natal);
+            }
+            transits.push(transit);
         }
 
         // Get lunar info
@@ -954,7 +2524,9 @@ impl StelliumServer {
 
                     // Find transiting planets in this house
                     let mut planets_in_house = Vec::new();
-                    let cusp_longitudes: [f64; 12] = houses.cusps.iter()
+                    let cusp_longitudes: [f64; 12] = houses
+                        .cusps
+                        .iter()
                         .map(|c| c.longitude)
                         .collect::<Vec<_>>()
                         .try_into()
@@ -964,7 +2536,11 @@ impl StelliumServer {
                         if transit_house == house_num {
                             planets_in_house.push(json!({
                                 "planet": planet.to_string(),
-                                "degree": format!("{}° {}", pos.to_zodiac_position().degree.round() as i32, pos.to_zodiac_position().sign),
+                                "degree": format!(
+                                    "{}° {}",
+                                    pos.to_zodiac_position().degree.round() as i32,
+                                    pos.to_zodiac_position().sign
+                                ),
                                 "retrograde": pos.is_retrograde
                             }));
                         }
@@ -974,14 +2550,28 @@ impl StelliumServer {
                     let mut aspects_to_ruler = Vec::new();
                     if let Some(natal_ruler_pos) = chart.planets.get(&ruler_planet) {
                         for (transit_planet, transit_pos) in &positions {
-                            if let Some((aspect_type, orb)) =
-                                find_aspect(transit_pos.longitude, natal_ruler_pos.longitude, false)
-                            {
-                                aspects_to_ruler.push(json!({
-                                    "transiting_planet": transit_planet.to_string(),
-                                    "aspect": aspect_type.to_string(),
-                                    "orb": (orb * 10.0).round() / 10.0
-                                }));
+                            if let Some((aspect_type, orb)) = orbs.find_aspect(
+                                *transit_planet,
+                                transit_pos.longitude,
+                                ruler_planet,
+                                natal_ruler_pos.longitude,
+                                false,
+                            ) {
+                                let rate = orb_rate(
+                                    transit_pos.longitude,
+                                    transit_pos.speed_longitude,
+                                    natal_ruler_pos.longitude,
+                                    0.0,
+                                    aspect_type,
+                                );
+                                aspects_to_ruler.push(with_motion(
+                                    json!({
+                                        "transiting_planet": transit_planet.to_string(),
+                                        "aspect": aspect_type.to_string(),
+                                        "orb": (orb * 10.0).round() / 10.0
+                                    }),
+                                    Some(rate),
+                                ));
                             }
                         }
                     }
@@ -1007,6 +2597,8 @@ impl StelliumServer {
         let response = json!({
             "success": true,
             "date": date_str,
+            "time": julian_day_to_timestamp(julian_day),
+            "timezone": timezone,
             "natal_chart": {
                 "name": chart.name,
                 "birth_date": chart.birth_date,
@@ -1025,49 +2617,1064 @@ impl StelliumServer {
         serde_json::to_string_pretty(&response).unwrap()
     }
 
-    fn get_relationship_transit_analysis(&self, input: GetRelationshipTransitAnalysisInput) -> String {
+    fn get_relationship_transit_analysis(
+        &self,
+        input: GetRelationshipTransitAnalysisInput,
+    ) -> String {
+        if let Err(e) = validate_orbs([input.synastry_orbs.as_ref(), input.transit_orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         // Load both charts
         let chart1 = match self.storage.get_chart(&input.person1_name) {
             Some(c) => c,
             None => {
                 return json!({
                     "success": false,
-                    "error": format!("Natal chart '{}' not found", input.person1_name)
+                    "error": format!("Natal chart '{}' not found", input.person1_name)
+                })
+                .to_string()
+            }
+        };
+
+        let chart2 = match self.storage.get_chart(&input.person2_name) {
+            Some(c) => c,
+            None => {
+                return json!({
+                    "success": false,
+                    "error": format!("Natal chart '{}' not found", input.person2_name)
+                })
+                .to_string()
+            }
+        };
+
+        let timezone = input.timezone.unwrap_or_else(|| chart1.timezone.clone());
+        let longitude = Some(chart1.longitude);
+
+        // Parse date (default to today in the query timezone)
+        let date_str = match input.date {
+            Some(d) => d,
+            None => match today_in(&timezone, longitude) {
+                Ok(d) => d,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            },
+        };
+        let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        let julian_day = match query_julian_day(
+            parsed_date,
+            input.time.as_deref(),
+            Some(&timezone),
+            longitude,
+        ) {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        // Calculate current planetary positions in each partner's zodiac
+        let positions1 = match calc_all_planets(julian_day, chart1.zodiac_mode()) {
+            Ok(p) => p,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Failed to calculate positions: {}", e)
+                })
+                .to_string()
+            }
+        };
+        let positions2 = if chart2.zodiac_mode() == chart1.zodiac_mode() {
+            positions1.clone()
+        } else {
+            match calc_all_planets(julian_day, chart2.zodiac_mode()) {
+                Ok(p) => p,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": format!("Failed to calculate positions: {}", e)
+                    })
+                    .to_string()
+                }
+            }
+        };
+
+        // Synastry compares both charts in the first person's zodiac
+        let synastry_chart1 = match chart_in_zodiac(chart1.clone(), chart1.zodiac_mode()) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+        let synastry_chart2 = match chart_in_zodiac(chart2.clone(), chart1.zodiac_mode()) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let orb_settings = self.storage.get_orb_settings();
+        let synastry_orbs = input.synastry_orbs.unwrap_or(orb_settings.synastry);
+        let transit_orbs = input.transit_orbs.unwrap_or(orb_settings.transit);
+
+        // Build synastry aspects
+        let mut synastry_aspects = Vec::new();
+        for planet1 in Planet::all() {
+            let pos1 = match chart1.planets.get(planet1) {
+                Some(p) => p,
+                None => continue,
+            };
+
+            for planet2 in Planet::all() {
+                let pos2 = match synastry_chart2.planets.get(planet2) {
+                    Some(p) => p,
+                    None => continue,
+                };
+
+                if let Some((aspect_type, orb)) = synastry_orbs.find_aspect(
+                    *planet1,
+                    pos1.longitude,
+                    *planet2,
+                    pos2.longitude,
+                    false,
+                ) {
+                    let rate = natal_orb_rate(
+                        &synastry_chart1,
+                        (ChartPoint::Planet(*planet1), pos1.longitude),
+                        &synastry_chart2,
+                        (ChartPoint::Planet(*planet2), pos2.longitude),
+                        aspect_type,
+                    );
+                    synastry_aspects.push(with_motion(
+                        json!({
+                            "person1_planet": planet1.to_string(),
+                            "person2_planet": planet2.to_string(),
+                            "aspect": aspect_type.to_string(),
+                            "orb": (orb * 100.0).round() / 100.0,
+                            "is_exact": orb < 1.0
+                        }),
+                        rate,
+                    ));
+                }
+            }
+        }
+
+        // Build transits for person 1
+        let mut person1_transits = Vec::new();
+        for (transit_planet, transit_pos) in &positions1 {
+            for natal_planet in Planet::all() {
+                let Some(natal_pos) = chart1.planets.get(natal_planet) else {
+                    continue;
+                };
+                if let Some((aspect_type, orb)) = transit_orbs.find_aspect(
+                    *transit_planet,
+                    transit_pos.longitude,
+                    *natal_planet,
+                    natal_pos.longitude,
+                    false,
+                ) {
+                    let rate = orb_rate(
+                        transit_pos.longitude,
+                        transit_pos.speed_longitude,
+                        natal_pos.longitude,
+                        0.0,
+                        aspect_type,
+                    );
+                    person1_transits.push(with_motion(
+                        json!({
+                            "transiting_planet": transit_planet.to_string(),
+                            "natal_planet": natal_planet.to_string(),
+                            "aspect": aspect_type.to_string(),
+                            "orb": (orb * 10.0).round() / 10.0
+                        }),
+                        Some(rate),
+                    ));
+                }
+            }
+        }
+
+        // Build transits for person 2
+        let mut person2_transits = Vec::new();
+        for (transit_planet, transit_pos) in &positions2 {
+            for natal_planet in Planet::all() {
+                let Some(natal_pos) = chart2.planets.get(natal_planet) else {
+                    continue;
+                };
+                if let Some((aspect_type, orb)) = transit_orbs.find_aspect(
+                    *transit_planet,
+                    transit_pos.longitude,
+                    *natal_planet,
+                    natal_pos.longitude,
+                    false,
+                ) {
+                    let rate = orb_rate(
+                        transit_pos.longitude,
+                        transit_pos.speed_longitude,
+                        natal_pos.longitude,
+                        0.0,
+                        aspect_type,
+                    );
+                    person2_transits.push(with_motion(
+                        json!({
+                            "transiting_planet": transit_planet.to_string(),
+                            "natal_planet": natal_planet.to_string(),
+                            "aspect": aspect_type.to_string(),
+                            "orb": (orb * 10.0).round() / 10.0
+                        }),
+                        Some(rate),
+                    ));
+                }
+            }
+        }
+
+        // Get lunar context
+        let phase_angle = calc_sun_moon_angle(julian_day).unwrap_or(0.0);
+        let moon_pos = positions1
+            .iter()
+            .find(|(p, _)| *p == Planet::Moon)
+            .map(|(_, pos)| pos.to_zodiac_position());
+
+        let lunar_context = json!({
+            "phase": LunarPhaseName::from_phase_angle(phase_angle).to_string(),
+            "moon_sign": moon_pos.as_ref().map(|p| p.sign.to_string()),
+            "illumination": (LunarPhaseName::illumination_from_angle(phase_angle) * 100.0).round()
+        });
+
+        // Count aspect types for summary
+        let mut harmonious = 0;
+        let mut challenging = 0;
+        for aspect in &synastry_aspects {
+            let aspect_str = aspect.get("aspect").and_then(|v| v.as_str()).unwrap_or("");
+            match aspect_str {
+                "trine" | "sextile" | "conjunction" => harmonious += 1,
+                "square" | "opposition" => challenging += 1,
+                _ => {}
+            }
+        }
+
+        let response = json!({
+            "success": true,
+            "date": date_str,
+            "time": julian_day_to_timestamp(julian_day),
+            "timezone": timezone,
+            "zodiac": chart1.zodiac_mode().to_string(),
+            "synastry": {
+                "aspects": synastry_aspects,
+                "summary": {
+                    "total_aspects": synastry_aspects.len(),
+                    "harmonious": harmonious,
+                    "challenging": challenging
+                }
+            },
+            "person1_transits": {
+                "name": chart1.name,
+                "aspects": person1_transits
+            },
+            "person2_transits": {
+                "name": chart2.name,
+                "aspects": person2_transits
+            },
+            "lunar_context": lunar_context
+        });
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    fn get_aspect_patterns(&self, input: GetAspectPatternsInput) -> String {
+        if let Err(e) = validate_orbs([input.orbs.as_ref()]) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
+        let source = input.source.unwrap_or_default();
+        let orb_settings = self.storage.get_orb_settings();
+        let orbs = input.orbs.clone().unwrap_or(match source {
+            PatternSource::Synastry => orb_settings.synastry,
+            _ => orb_settings.natal,
+        });
+
+        let (bodies, context) = match self.pattern_bodies(&input, source) {
+            Ok(b) => b,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let patterns = find_aspect_patterns(&bodies, &orbs);
+
+        let mut response = json!({
+            "source": source.to_string(),
+            "patterns": patterns,
+            "count": patterns.len()
+        });
+        if let (Value::Object(map), Value::Object(context)) = (&mut response, context) {
+            map.extend(context);
+        }
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    /// Positions (with houses where known) for pattern detection, plus context for the response
+    fn pattern_bodies(
+        &self,
+        input: &GetAspectPatternsInput,
+        source: PatternSource,
+    ) -> Result<(Vec<PatternBody>, Value), String> {
+        let find_chart = |name: Option<&String>, role: &str| -> Result<NatalChart, String> {
+            let name =
+                name.ok_or_else(|| format!("{} is required for {} patterns", role, source))?;
+            self.storage
+                .get_chart(name)
+                .ok_or_else(|| format!("Natal chart '{}' not found", name))
+        };
+        let natal_bodies = |chart: &NatalChart,
+                            owner: Option<&str>,
+                            cusps: Option<&[f64; 12]>|
+         -> Vec<PatternBody> {
+            Planet::all()
+                .iter()
+                .filter_map(|planet| {
+                    let position = chart.planets.get(planet)?;
+                    Some(PatternBody {
+                        name: match owner {
+                            Some(owner) => format!("{}: {}", owner, planet),
+                            None => planet.to_string(),
+                        },
+                        planet: *planet,
+                        longitude: position.longitude,
+                        house: cusps.map(|c| planet_in_house(position.longitude, c)),
+                        owner: owner.map(str::to_string),
+                    })
+                })
+                .collect()
+        };
+
+        match source {
+            PatternSource::Natal => {
+                let chart = find_chart(input.name.as_ref(), "name")?;
+                let cusps = chart.cusp_longitudes();
+                let bodies = natal_bodies(&chart, None, cusps.as_ref());
+                Ok((bodies, json!({ "name": chart.name })))
+            }
+            PatternSource::Transit => {
+                let chart = input
+                    .name
+                    .as_ref()
+                    .map(|_| find_chart(input.name.as_ref(), "name"))
+                    .transpose()?;
+                let timezone = input
+                    .timezone
+                    .clone()
+                    .or_else(|| chart.as_ref().map(|c| c.timezone.clone()));
+                let longitude = chart.as_ref().map(|c| c.longitude);
+                let date = match &input.date {
+                    Some(d) => d.clone(),
+                    None => today_in(timezone.as_deref().unwrap_or("UTC"), longitude)?,
+                };
+                let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                    .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
+                let julian_day = query_julian_day(
+                    parsed_date,
+                    input.time.as_deref(),
+                    timezone.as_deref(),
+                    longitude,
+                )?;
+
+                let zodiac = chart.as_ref().map(|c| c.zodiac_mode()).unwrap_or_default();
+                let positions = calc_all_planets(julian_day, zodiac)
+                    .map_err(|e| format!("Failed to calculate positions: {}", e))?;
+                let cusps = chart.as_ref().and_then(|c| c.cusp_longitudes());
+                let bodies = Planet::all()
+                    .iter()
+                    .filter_map(|planet| {
+                        let (_, position) = positions.iter().find(|(p, _)| p == planet)?;
+                        Some(PatternBody {
+                            name: planet.to_string(),
+                            planet: *planet,
+                            longitude: position.longitude,
+                            house: cusps
+                                .as_ref()
+                                .map(|c| planet_in_house(position.longitude, c)),
+                            owner: None,
+                        })
+                    })
+                    .collect();
+
+                let mut context = json!({
+                    "date": date,
+                    "time": julian_day_to_timestamp(julian_day)
+                });
+                if let Some(chart) = chart {
+                    context["houses_from"] = json!(chart.name);
+                }
+                Ok((bodies, context))
+            }
+            PatternSource::Composite | PatternSource::Synastry => {
+                let chart1 = find_chart(input.name.as_ref(), "name")?;
+                let chart2 = find_chart(input.person2_name.as_ref(), "person2_name")?;
+                // Both charts in the first person's zodiac
+                let chart2 = chart_in_zodiac(chart2, chart1.zodiac_mode())?;
+                let cusps1 = chart1.cusp_longitudes();
+                let context = json!({ "person1": chart1.name, "person2": chart2.name });
+
+                if source == PatternSource::Synastry {
+                    // Overlay: the second person's planets fall in the first person's houses
+                    let mut bodies = natal_bodies(&chart1, Some(&chart1.name), cusps1.as_ref());
+                    bodies.extend(natal_bodies(&chart2, Some(&chart2.name), cusps1.as_ref()));
+                    return Ok((bodies, context));
+                }
+
+                // Midpoint composite, with house cusps at the midpoints of both charts' cusps
+                let composite_cusps = cusps1
+                    .zip(chart2.cusp_longitudes())
+                    .map(|(c1, c2)| std::array::from_fn(|i| midpoint(c1[i], c2[i])));
+                let bodies = Planet::all()
+                    .iter()
+                    .filter_map(|planet| {
+                        let longitude = midpoint(
+                            chart1.planets.get(planet)?.longitude,
+                            chart2.planets.get(planet)?.longitude,
+                        );
+                        Some(PatternBody {
+                            name: planet.to_string(),
+                            planet: *planet,
+                            longitude,
+                            house: composite_cusps
+                                .as_ref()
+                                .map(|c| planet_in_house(longitude, c)),
+                            owner: None,
+                        })
+                    })
+                    .collect();
+                Ok((bodies, context))
+            }
+        }
+    }
+
+    fn get_eclipses(&self, input: GetEclipsesInput) -> String {
+        let default_chart = self.storage.get_default_chart();
+        let timezone_name = input
+            .timezone
+            .or_else(|| default_chart.as_ref().map(|c| c.timezone.clone()));
+        let longitude = default_chart.as_ref().map(|c| c.longitude);
+        let timezone = match parse_timezone(timezone_name.as_deref(), longitude) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let date_str = match input.date {
+            Some(d) => d,
+            None => match today_in(timezone_name.as_deref().unwrap_or("UTC"), longitude) {
+                Ok(d) => d,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            },
+        };
+        let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+        // Dates are local days in the timezone
+        let start_jd =
+            match query_julian_day(parsed_date, None, timezone_name.as_deref(), longitude) {
+                Ok(jd) => jd,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            };
+
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .or_else(|| default_chart.as_ref().map(|c| c.zodiac_mode()))
+            .unwrap_or_default();
+
+        let eclipses = match input.end_date {
+            Some(end_date) => {
+                let parsed_end =
+                    match NaiveDate::parse_from_str(&end_date, "%Y-%m-%d") {
+                        Ok(d) => d,
+                        Err(e) => return json!({
+                            "success": false,
+                            "error": format!("Invalid end_date format: {}. Expected YYYY-MM-DD", e)
+                        })
+                        .to_string(),
+                    };
+                if parsed_end < parsed_date {
+                    return json!({
+                        "success": false,
+                        "error": "end_date must not be before date"
+                    })
+                    .to_string();
+                }
+                if (parsed_end - parsed_date).num_days() > MAX_ECLIPSE_RANGE_DAYS {
+                    return json!({
+                        "success": false,
+                        "error": format!(
+                            "end_date must be at most {} days (ten years) after date",
+                            MAX_ECLIPSE_RANGE_DAYS
+                        )
+                    })
+                    .to_string();
+                }
+                // Inclusive of the whole local end date
+                query_julian_day(
+                    parsed_end + Days::new(1),
+                    None,
+                    timezone_name.as_deref(),
+                    longitude,
+                )
+                .and_then(|end_jd| find_eclipses(start_jd, end_jd, input.kind))
+            }
+            None => {
+                let backward = input.direction.unwrap_or_default() == SearchDirection::Previous;
+                let count = input.count.unwrap_or(4).clamp(1, 50);
+                find_eclipse_sequence(start_jd, input.kind, backward, count)
+            }
+        };
+
+        let summaries: Result<Vec<EclipseSummary>, String> = eclipses.and_then(|eclipses| {
+            eclipses
+                .iter()
+                .map(|eclipse| eclipse_summary(eclipse, zodiac, timezone.as_ref()))
+                .collect()
+        });
+        let summaries = match summaries {
+            Ok(s) => s,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let response = json!({
+            "date": date_str,
+            "timezone": timezone_name,
+            "zodiac": zodiac.to_string(),
+            "eclipses": summaries,
+            "count": summaries.len()
+        });
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    fn get_eclipse_circumstances(&self, input: GetEclipseCircumstancesInput) -> String {
+        let chart = match input.name.as_deref() {
+            Some(name) => match self.storage.get_chart(name) {
+                Some(c) => Some(c),
+                None => {
+                    return json!({
+                        "success": false,
+                        "error": format!("Natal chart '{}' not found", name)
+                    })
+                    .to_string()
+                }
+            },
+            None => self.storage.get_default_chart(),
+        };
+
+        let timezone_name = input
+            .timezone
+            .or_else(|| chart.as_ref().map(|c| c.timezone.clone()));
+        let longitude = chart.as_ref().map(|c| c.longitude);
+        let timezone = match parse_timezone(timezone_name.as_deref(), longitude) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let date_str = match input.date {
+            Some(d) => d,
+            None => match today_in(timezone_name.as_deref().unwrap_or("UTC"), longitude) {
+                Ok(d) => d,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            },
+        };
+        let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+
+        // The search starts at local midnight in the timezone
+        let eclipse = match query_julian_day(parsed_date, None, timezone_name.as_deref(), longitude)
+            .and_then(|jd| find_eclipse(jd, input.kind, false))
+        {
+            Ok(e) => e,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+        let zodiac = chart.as_ref().map(|c| c.zodiac_mode()).unwrap_or_default();
+        let summary = match eclipse_summary(&eclipse, zodiac, timezone.as_ref()) {
+            Ok(s) => s,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let mut response = json!({
+            "eclipse": summary
+        });
+
+        if let Some(ref chart) = chart {
+            let local = match local_eclipse(&eclipse, chart.latitude, chart.longitude) {
+                Ok(l) => l,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            };
+            let circumstances = match local {
+                Some(local) => LocalEclipseCircumstances {
+                    visible: true,
+                    eclipse_type: Some(local.eclipse_type),
+                    maximum: Some(julian_day_to_timestamp(local.maximum)),
+                    maximum_local: local_timestamp(local.maximum, timezone.as_ref()),
+                    magnitude: Some((local.magnitude * 10000.0).round() / 10000.0),
+                    obscuration: local.obscuration.map(|o| (o * 10000.0).round() / 10000.0),
+                    altitude: Some((local.altitude * 10.0).round() / 10.0),
+                    azimuth: Some((local.azimuth * 10.0).round() / 10.0),
+                    contacts: local
+                        .contacts
+                        .iter()
+                        .map(|contact| LocalEclipseContact {
+                            contact: contact.name.to_string(),
+                            time: julian_day_to_timestamp(contact.julian_day),
+                            local_time: local_timestamp(contact.julian_day, timezone.as_ref()),
+                            altitude: (contact.altitude * 10.0).round() / 10.0,
+                            visible: contact.altitude > 0.0,
+                        })
+                        .collect(),
+                },
+                None => LocalEclipseCircumstances {
+                    visible: false,
+                    eclipse_type: None,
+                    maximum: None,
+                    maximum_local: None,
+                    magnitude: None,
+                    obscuration: None,
+                    altitude: None,
+                    azimuth: None,
+                    contacts: Vec::new(),
+                },
+            };
+
+            response["location"] = json!({
+                "chart": chart.name,
+                "place": chart.birth_location,
+                "latitude": chart.latitude,
+                "longitude": chart.longitude
+            });
+            response["local_circumstances"] = json!(circumstances);
+        }
+
+        if eclipse.kind == EclipseKind::Solar && input.include_path.unwrap_or(true) {
+            let path = match find_eclipse_path(&eclipse) {
+                Ok(p) => p,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            };
+            let observer = chart
+                .as_ref()
+                .map(|c| (c.name.as_str(), (c.longitude, c.latitude)));
+            response["path"] = eclipse_geojson(&path, &summary, observer);
+        }
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    fn get_natal_eclipses(&self, input: GetNatalEclipsesInput) -> String {
+        let chart = match input.name.as_deref() {
+            Some(name) => self.storage.get_chart(name),
+            None => self.storage.get_default_chart(),
+        };
+        let chart = match chart {
+            Some(c) => c,
+            None => return json!({
+                "success": false,
+                "error": match input.name {
+                    Some(name) => format!("Natal chart '{}' not found", name),
+                    None => "No natal chart stored. Please store a natal chart first.".to_string(),
+                }
+            })
+            .to_string(),
+        };
+
+        let parsed_start = match NaiveDate::parse_from_str(&input.start_date, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid start_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+        let parsed_end = match NaiveDate::parse_from_str(&input.end_date, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid end_date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+        if parsed_end < parsed_start {
+            return json!({
+                "success": false,
+                "error": "end_date must not be before start_date"
+            })
+            .to_string();
+        }
+        if (parsed_end - parsed_start).num_days() > MAX_ECLIPSE_RANGE_DAYS {
+            return json!({
+                "success": false,
+                "error": format!(
+                    "end_date must be at most {} days (ten years) after start_date",
+                    MAX_ECLIPSE_RANGE_DAYS
+                )
+            })
+            .to_string();
+        }
+
+        let orb = input.orb.unwrap_or(DEFAULT_ECLIPSE_ORB);
+        if !(0.0..=10.0).contains(&orb) {
+            return json!({
+                "success": false,
+                "error": format!("Invalid orb {}: must be between 0 and 10 degrees", orb)
+            })
+            .to_string();
+        }
+        let include_oppositions = input.include_oppositions.unwrap_or(true);
+
+        let timezone_name = input.timezone.unwrap_or_else(|| chart.timezone.clone());
+        let timezone = match parse_timezone(Some(&timezone_name), Some(chart.longitude)) {
+            Ok(tz) => tz,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .unwrap_or_else(|| chart.zodiac_mode());
+        let chart = match chart_in_zodiac(chart, zodiac) {
+            Ok(c) => c,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let extra = input.points.unwrap_or_default();
+        let points = chart.natal_points(&extra, false);
+        let cusps = chart.cusp_longitudes();
+        let house_of = |longitude: f64| cusps.as_ref().map(|c| planet_in_house(longitude, c));
+
+        // The eclipse degree is the Sun's for solar eclipses and the Moon's for lunar ones
+        let natal_eclipse = |eclipse: &Eclipse| -> Result<NatalEclipse, String> {
+            let summary = eclipse_summary(eclipse, zodiac, timezone.as_ref())?;
+            let longitude =
+                calc_planet_position(eclipse.luminary(), eclipse.maximum, zodiac)?.longitude;
+
+            let mut contacts: Vec<NatalEclipseContact> = points
+                .iter()
+                .filter_map(|(point, point_longitude)| {
+                    let (aspect, contact_orb) = conjunction_or_opposition(
+                        longitude,
+                        *point_longitude,
+                        orb,
+                        include_oppositions,
+                    )?;
+                    let house = house_of(*point_longitude);
+                    Some(NatalEclipseContact {
+                        point: point.to_string(),
+                        aspect,
+                        orb: (contact_orb * 100.0).round() / 100.0,
+                        natal_position: display_position(*point_longitude),
+                        house,
+                        life_area: house.and_then(LifeArea::from_house),
+                    })
+                })
+                .collect();
+            contacts.sort_by(|a, b| a.orb.total_cmp(&b.orb));
+
+            let house = house_of(longitude);
+            Ok(NatalEclipse {
+                eclipse: summary,
+                house,
+                life_area: house.and_then(LifeArea::from_house),
+                contacts,
+            })
+        };
+
+        // Dates are local days in the timezone, inclusive of the whole end date
+        let eclipses: Result<Vec<NatalEclipse>, String> = query_julian_day(
+            parsed_start,
+            None,
+            Some(&timezone_name),
+            Some(chart.longitude),
+        )
+        .and_then(|start_jd| {
+            let end_jd = query_julian_day(
+                parsed_end + Days::new(1),
+                None,
+                Some(&timezone_name),
+                Some(chart.longitude),
+            )?;
+            find_eclipses(start_jd, end_jd, input.kind)
+        })
+        .and_then(|eclipses| eclipses.iter().map(natal_eclipse).collect());
+        let eclipses: Vec<NatalEclipse> = match eclipses {
+            Ok(e) => e.into_iter().filter(|e| !e.contacts.is_empty()).collect(),
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let prenatal: Result<Vec<NatalEclipse>, String> = birth_julian_day(&chart)
+            .and_then(prenatal_eclipses)
+            .and_then(|prenatal| prenatal.iter().map(natal_eclipse).collect());
+        let prenatal = match prenatal {
+            Ok(p) => p,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string()
+            }
+        };
+
+        let response = json!({
+            "natal_chart": chart.name,
+            "start_date": input.start_date,
+            "end_date": input.end_date,
+            "timezone": timezone_name,
+            "zodiac": zodiac.to_string(),
+            "orb": orb,
+            "eclipses": eclipses,
+            "count": eclipses.len(),
+            "prenatal_eclipses": prenatal
+        });
+
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    fn get_fixed_stars(&self, input: GetFixedStarsInput) -> String {
+        let natal_chart = match input.name.as_deref() {
+            Some(name) => match self.storage.get_chart(name) {
+                Some(c) => Some(c),
+                None => {
+                    return json!({
+                        "success": false,
+                        "error": format!("Natal chart '{}' not found", name)
+                    })
+                    .to_string()
+                }
+            },
+            None => self.storage.get_default_chart(),
+        };
+
+        let timezone = input
+            .timezone
+            .or_else(|| natal_chart.as_ref().map(|c| c.timezone.clone()));
+        let longitude = natal_chart.as_ref().map(|c| c.longitude);
+        let date_str = match input.date {
+            Some(d) => d,
+            None => match today_in(timezone.as_deref().unwrap_or("UTC"), longitude) {
+                Ok(d) => d,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
+                }
+            },
+        };
+        let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                })
+                .to_string()
+            }
+        };
+        let julian_day = match query_julian_day(
+            parsed_date,
+            input.time.as_deref(),
+            timezone.as_deref(),
+            longitude,
+        ) {
+            Ok(jd) => jd,
+            Err(e) => {
+                return json!({
+                    "success": false,
+                    "error": e
                 })
                 .to_string()
             }
         };
 
-        let chart2 = match self.storage.get_chart(&input.person2_name) {
-            Some(c) => c,
-            None => {
+        let orb = input.orb.unwrap_or(DEFAULT_FIXED_STAR_ORB);
+        if !(0.0..=10.0).contains(&orb) {
+            return json!({
+                "success": false,
+                "error": format!("Invalid orb {}: must be between 0 and 10 degrees", orb)
+            })
+            .to_string();
+        }
+        let include_oppositions = input.include_oppositions.unwrap_or(true);
+
+        let zodiac = ZodiacMode::from_options(input.zodiac, input.ayanamsa)
+            .or_else(|| natal_chart.as_ref().map(|c| c.zodiac_mode()))
+            .unwrap_or_default();
+        let natal_chart = match natal_chart.map(|c| chart_in_zodiac(c, zodiac)).transpose() {
+            Ok(c) => c,
+            Err(e) => {
                 return json!({
                     "success": false,
-                    "error": format!("Natal chart '{}' not found", input.person2_name)
+                    "error": e
                 })
                 .to_string()
             }
         };
 
-        // Parse date (default to today)
-        let date_str = input.date.unwrap_or_else(|| {
-            chrono::Local::now().format("%Y-%m-%d").to_string()
-        });
-        let parsed_date = match NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
-            Ok(d) => d,
+        let names: Vec<String> = match input.stars.filter(|s| !s.is_empty()) {
+            Some(stars) => stars,
+            None => bundled_star_names()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        };
+        let star_positions = |jd: f64| -> Result<Vec<FixedStarPosition>, String> {
+            names
+                .iter()
+                .map(|name| calc_fixed_star(name, jd, zodiac))
+                .collect()
+        };
+
+        // Stars move with precession (about 1° in 72 years), so positions are taken for the date
+        let stars = match star_positions(julian_day) {
+            Ok(s) => s,
             Err(e) => {
                 return json!({
                     "success": false,
-                    "error": format!("Invalid date format: {}. Expected YYYY-MM-DD", e)
+                    "error": e
                 })
                 .to_string()
             }
         };
 
-        let julian_day = date_to_julian_day(parsed_date);
-
-        // Calculate current planetary positions
-        let positions = match calc_all_planets(julian_day) {
+        let extra = input.points.unwrap_or_default();
+        let planets = match calc_planets(&transiting_bodies(&extra), julian_day, zodiac) {
             Ok(p) => p,
             Err(e) => {
                 return json!({
@@ -1078,111 +3685,144 @@ impl StelliumServer {
             }
         };
 
-        // Build synastry aspects
-        let mut synastry_aspects = Vec::new();
-        for planet1 in Planet::all() {
-            let pos1 = match chart1.planets.get(planet1) {
-                Some(p) => p,
-                None => continue,
-            };
-
-            for planet2 in Planet::all() {
-                let pos2 = match chart2.planets.get(planet2) {
-                    Some(p) => p,
-                    None => continue,
-                };
-
-                if let Some((aspect_type, orb)) = find_aspect(pos1.longitude, pos2.longitude, false) {
-                    synastry_aspects.push(json!({
-                        "person1_planet": planet1.to_string(),
-                        "person2_planet": planet2.to_string(),
-                        "aspect": aspect_type.to_string(),
-                        "orb": (orb * 100.0).round() / 100.0,
-                        "is_exact": orb < 1.0
+        let mut transit_contacts = Vec::new();
+        for star in &stars {
+            for (planet, position) in &planets {
+                if let Some((aspect, star_orb)) = conjunction_or_opposition(
+                    star.longitude,
+                    position.longitude,
+                    orb,
+                    include_oppositions,
+                ) {
+                    transit_contacts.push(json!({
+                        "star": star.name,
+                        "planet": planet.to_string(),
+                        "aspect": aspect,
+                        "orb": (star_orb * 100.0).round() / 100.0
                     }));
                 }
             }
         }
 
-        // Build transits for person 1
-        let mut person1_transits = Vec::new();
-        for (transit_planet, transit_pos) in &positions {
-            for (natal_planet, natal_pos) in &chart1.planets {
-                if let Some((aspect_type, orb)) =
-                    find_aspect(transit_pos.longitude, natal_pos.longitude, false)
-                {
-                    person1_transits.push(json!({
-                        "transiting_planet": transit_planet.to_string(),
-                        "natal_planet": natal_planet.to_string(),
-                        "aspect": aspect_type.to_string(),
-                        "orb": (orb * 10.0).round() / 10.0
-                    }));
+        let star_summaries: Vec<Value> = stars
+            .iter()
+            .map(|star| {
+                json!({
+                    "star": star.name,
+                    "nomenclature": star.nomenclature,
+                    "position": display_position(star.longitude),
+                    "latitude": (star.latitude * 100.0).round() / 100.0,
+                    "magnitude": (star.magnitude * 100.0).round() / 100.0
+                })
+            })
+            .collect();
+
+        let mut response = json!({
+            "date": date_str,
+            "time": julian_day_to_timestamp(julian_day),
+            "timezone": timezone,
+            "zodiac": zodiac.to_string(),
+            "orb": orb,
+            "stars": star_summaries,
+            "transit_contacts": transit_contacts
+        });
+
+        // Natal contacts use the stars' positions at the birth moment
+        if let Some(chart) = natal_chart {
+            let birth_stars = match birth_julian_day(&chart).and_then(star_positions) {
+                Ok(s) => s,
+                Err(e) => {
+                    return json!({
+                        "success": false,
+                        "error": e
+                    })
+                    .to_string()
                 }
-            }
-        }
+            };
 
-        // Build transits for person 2
-        let mut person2_transits = Vec::new();
-        for (transit_planet, transit_pos) in &positions {
-            for (natal_planet, natal_pos) in &chart2.planets {
-                if let Some((aspect_type, orb)) =
-                    find_aspect(transit_pos.longitude, natal_pos.longitude, false)
-                {
-                    person2_transits.push(json!({
-                        "transiting_planet": transit_planet.to_string(),
-                        "natal_planet": natal_planet.to_string(),
-                        "aspect": aspect_type.to_string(),
-                        "orb": (orb * 10.0).round() / 10.0
-                    }));
+            let mut natal_contacts = Vec::new();
+            for star in &birth_stars {
+                for (point, point_longitude) in chart.natal_points(&extra, false) {
+                    if let Some((aspect, star_orb)) = conjunction_or_opposition(
+                        star.longitude,
+                        point_longitude,
+                        orb,
+                        include_oppositions,
+                    ) {
+                        natal_contacts.push(json!({
+                            "star": star.name,
+                            "point": point.to_string(),
+                            "aspect": aspect,
+                            "orb": (star_orb * 100.0).round() / 100.0,
+                            "star_position": display_position(star.longitude)
+                        }));
+                    }
                 }
             }
+
+            response["natal_chart"] = json!(chart.name);
+            response["natal_contacts"] = Value::Array(natal_contacts);
         }
 
-        // Get lunar context
-        let phase_angle = calc_sun_moon_angle(julian_day).unwrap_or(0.0);
-        let moon_pos = positions
-            .iter()
-            .find(|(p, _)| *p == Planet::Moon)
-            .map(|(_, pos)| pos.to_zodiac_position());
+        serde_json::to_string_pretty(&response).unwrap()
+    }
 
-        let lunar_context = json!({
-            "phase": LunarPhaseName::from_phase_angle(phase_angle).to_string(),
-            "moon_sign": moon_pos.as_ref().map(|p| p.sign.to_string()),
-            "illumination": (LunarPhaseName::illumination_from_angle(phase_angle) * 100.0).round()
+    fn get_orb_settings(&self) -> String {
+        // Keyed the same way as the aspect_orbs and planet_orbs inputs
+        let response = json!({
+            "orb_settings": self.storage.get_orb_settings(),
+            "default_aspect_orbs": AspectType::all_aspects()
+                .iter()
+                .map(|a| (*a, a.default_orb()))
+                .collect::<HashMap<_, _>>(),
+            "default_planet_orbs": Planet::all()
+                .iter()
+                .chain(Planet::additional())
+                .map(|p| (*p, OrbPolicy::default_planet_orb(*p)))
+                .collect::<HashMap<_, _>>(),
+            "default_parallel_orb": DeclinationAspect::default_orb()
         });
 
-        // Count aspect types for summary
-        let mut harmonious = 0;
-        let mut challenging = 0;
-        for aspect in &synastry_aspects {
-            let aspect_str = aspect.get("aspect").and_then(|v| v.as_str()).unwrap_or("");
-            match aspect_str {
-                "trine" | "sextile" | "conjunction" => harmonious += 1,
-                "square" | "opposition" => challenging += 1,
-                _ => {}
+        serde_json::to_string_pretty(&response).unwrap()
+    }
+
+    fn set_orb_settings(&self, input: SetOrbSettingsInput) -> String {
+        let mut settings = if input.reset.unwrap_or(false) {
+            OrbSettings::default()
+        } else {
+            self.storage.get_orb_settings()
+        };
+        if let Some(natal) = input.natal {
+            settings.natal = natal;
+        }
+        if let Some(transit) = input.transit {
+            settings.transit = transit;
+        }
+        if let Some(synastry) = input.synastry {
+            settings.synastry = synastry;
+        }
+
+        for policy in [&settings.natal, &settings.transit, &settings.synastry] {
+            if let Err(e) = policy.validate() {
+                return json!({
+                    "success": false,
+                    "error": e
+                })
+                .to_string();
             }
         }
 
+        if let Err(e) = self.storage.set_orb_settings(settings.clone()) {
+            return json!({
+                "success": false,
+                "error": e
+            })
+            .to_string();
+        }
+
         let response = json!({
             "success": true,
-            "date": date_str,
-            "synastry": {
-                "aspects": synastry_aspects,
-                "summary": {
-                    "total_aspects": synastry_aspects.len(),
-                    "harmonious": harmonious,
-                    "challenging": challenging
-                }
-            },
-            "person1_transits": {
-                "name": chart1.name,
-                "aspects": person1_transits
-            },
-            "person2_transits": {
-                "name": chart2.name,
-                "aspects": person2_transits
-            },
-            "lunar_context": lunar_context
+            "orb_settings": settings
         });
 
         serde_json::to_string_pretty(&response).unwrap()
@@ -1212,9 +3852,14 @@ impl StelliumServer {
             ),
             Tool::new(
                 "get_transit_report",
-                "Get a summary of major astrological events over a specified date range including sign changes, aspects to natal chart, and lunar events.",
+                "Get a summary of major astrological events over a specified date range including sign changes, stations, transit-to-natal aspect windows with exact perfection times, and lunar events.",
                 schema_to_value::<TransitReportInput>(),
             ),
+            Tool::new(
+                "get_void_of_course_calendar",
+                "List every void-of-course Moon period between two dates with exact start and end times, in UTC and optionally a local timezone.",
+                schema_to_value::<VoidOfCourseCalendarInput>(),
+            ),
             Tool::new(
                 "list_natal_charts",
                 "List all stored natal charts by name.",
@@ -1250,10 +3895,51 @@ impl StelliumServer {
                 "Combined relationship analysis with synastry aspects plus both partners' current transits and lunar context for a given date.",
                 schema_to_value::<GetRelationshipTransitAnalysisInput>(),
             ),
+            Tool::new(
+                "get_aspect_patterns",
+                "Detect aspect patterns (grand trine, T-square, grand cross, yod, kite, mystic rectangle, grand sextile, stellia by sign and house) in a natal chart, the sky at a moment, a composite chart or a synastry overlay.",
+                schema_to_value::<GetAspectPatternsInput>(),
+            ),
+            Tool::new(
+                "get_eclipses",
+                "Find solar and lunar eclipses: the next or previous few from a date, or all within a date range. Gives type (total, annular, hybrid, partial, penumbral), time of greatest eclipse, phase times, magnitude, saros series and the eclipse degree.",
+                schema_to_value::<GetEclipsesInput>(),
+            ),
+            Tool::new(
+                "get_eclipse_circumstances",
+                "Local circumstances of an eclipse at a natal chart's birth location (contact times, magnitude, obscuration, altitude), plus the solar eclipse path (central line and umbral and penumbral limits) as a GeoJSON FeatureCollection.",
+                schema_to_value::<GetEclipseCircumstancesInput>(),
+            ),
+            Tool::new(
+                "get_natal_eclipses",
+                "Find eclipses in a date range that fall on a natal chart: conjunct or opposite natal planets, angles and the nodes within an orb, with the natal house and life area affected. Also gives the prenatal eclipses (the last solar and lunar eclipse before birth).",
+                schema_to_value::<GetNatalEclipsesInput>(),
+            ),
+            Tool::new(
+                "get_fixed_stars",
+                "Get fixed star positions for a date (with precession) and their conjunctions and oppositions to natal planets and angles and to transiting planets. Uses a bundled catalog of the royal, Behenian and other bright stars.",
+                schema_to_value::<GetFixedStarsInput>(),
+            ),
+            Tool::new(
+                "get_orb_settings",
+                "Get the server's default orb settings for natal, transit and synastry aspects, plus the built-in aspect and planet orbs.",
+                empty_schema(),
+            ),
+            Tool::new(
+                "set_orb_settings",
+                "Set the server's default orb settings: which aspects are enabled, per-aspect or per-planet orbs (or moieties) and a luminary bonus, separately for natal, transit and synastry. Saved permanently.",
+                schema_to_value::<SetOrbSettingsInput>(),
+            ),
         ]
     }
 }
 
+impl Default for StelliumServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ServerHandler for StelliumServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -1314,7 +4000,43 @@ impl ServerHandler for StelliumServer {
                     .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
                 self.get_transit_report(input)
             }
+            "get_void_of_course_calendar" => {
+                let input: VoidOfCourseCalendarInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_void_of_course_calendar(input)
+            }
             "list_natal_charts" => self.list_natal_charts(),
+            "get_aspect_patterns" => {
+                let input: GetAspectPatternsInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_aspect_patterns(input)
+            }
+            "get_eclipses" => {
+                let input: GetEclipsesInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_eclipses(input)
+            }
+            "get_eclipse_circumstances" => {
+                let input: GetEclipseCircumstancesInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_eclipse_circumstances(input)
+            }
+            "get_natal_eclipses" => {
+                let input: GetNatalEclipsesInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_natal_eclipses(input)
+            }
+            "get_fixed_stars" => {
+                let input: GetFixedStarsInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.get_fixed_stars(input)
+            }
+            "get_orb_settings" => self.get_orb_settings(),
+            "set_orb_settings" => {
+                let input: SetOrbSettingsInput = serde_json::from_value(args)
+                    .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
+                self.set_orb_settings(input)
+            }
             "search_natal_charts" => {
                 let input: SearchNatalChartsInput = serde_json::from_value(args)
                     .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None))?;
@@ -1357,30 +4079,739 @@ impl ServerHandler for StelliumServer {
     }
 }
 
-/// Find previous lunar phase (search backwards)
-fn find_previous_lunar_phase(start_jd: f64, target_angle: f64, max_days: i32) -> Option<f64> {
-    let step = 0.5;
-    let mut jd = start_jd;
-    let end_jd = start_jd - max_days as f64;
+/// Orb rate of a synastry aspect from both charts' speeds at birth (None for charts stored without speeds)
+/// Angles and cusps are treated as fixed points.
+fn natal_orb_rate(
+    chart1: &NatalChart,
+    (point1, longitude1): (ChartPoint, f64),
+    chart2: &NatalChart,
+    (point2, longitude2): (ChartPoint, f64),
+    aspect_type: AspectType,
+) -> Option<f64> {
+    let speed = |chart: &NatalChart, point: ChartPoint| match point.planet() {
+        Some(planet) => chart.planet_positions.get(&planet)?.speed,
+        None => Some(0.0),
+    };
+    Some(orb_rate(
+        longitude1,
+        speed(chart1, point1)?,
+        longitude2,
+        speed(chart2, point2)?,
+        aspect_type,
+    ))
+}
+
+/// Natal aspects as a grid: each point maps the points listed after it to their aspect
+fn aspect_grid(aspects: &[NatalAspect]) -> Value {
+    let mut grid = serde_json::Map::new();
+    for aspect in aspects {
+        let row = grid
+            .entry(aspect.point1.clone())
+            .or_insert_with(|| json!({}));
+        row[aspect.point2.as_str()] = json!({
+            "aspect": aspect.aspect,
+            "orb": aspect.orb,
+            "motion": aspect.motion
+        });
+    }
+    Value::Object(grid)
+}
+
+/// Check the orb policies passed with a call (the stored ones are checked when they are set)
+fn validate_orbs<const N: usize>(policies: [Option<&OrbPolicy>; N]) -> Result<(), String> {
+    policies
+        .into_iter()
+        .flatten()
+        .try_for_each(OrbPolicy::validate)
+}
 
-    let mut prev_angle = calc_sun_moon_angle(jd).ok()?;
+/// Add an aspect's applying/separating motion and orb rate to its JSON, when the rate is known
+fn with_motion(mut aspect: Value, orb_rate: Option<f64>) -> Value {
+    if let (Some(fields), Some(orb_motion)) = (
+        aspect.as_object_mut(),
+        orb_rate.map(OrbMotion::from_orb_rate),
+    ) {
+        fields.insert("motion".to_string(), json!(orb_motion.motion));
+        fields.insert("rate".to_string(), json!(orb_motion.rate));
+    }
+    aspect
+}
 
-    while jd > end_jd {
-        jd -= step;
-        let current_angle = calc_sun_moon_angle(jd).ok()?;
+/// House number as an ordinal ("1st", "2nd", "12th")
+fn ordinal(house: u8) -> String {
+    let suffix = match house {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    };
+    format!("{}{}", house, suffix)
+}
 
-        let crossed = if target_angle < 10.0 || target_angle > 350.0 {
-            current_angle > 350.0 && prev_angle < 10.0
-        } else {
-            current_angle > target_angle && prev_angle <= target_angle
+/// Midpoint of two longitudes along the shorter arc
+fn midpoint(longitude1: f64, longitude2: f64) -> f64 {
+    (longitude1 + angle_past(longitude2, longitude1) / 2.0).rem_euclid(360.0)
+}
+
+/// Position rounded to 0.01° for display
+fn display_position(longitude: f64) -> ZodiacPosition {
+    let position = ZodiacPosition::from_longitude(longitude);
+    ZodiacPosition {
+        degree: (position.degree * 100.0).round() / 100.0,
+        longitude: (position.longitude * 100.0).round() / 100.0,
+        ..position
+    }
+}
+
+/// Parse an optional timezone (IANA name, UTC offset, or LMT at the given longitude)
+fn parse_timezone(
+    timezone: Option<&str>,
+    longitude: Option<f64>,
+) -> Result<Option<LocalTimezone>, String> {
+    timezone
+        .map(|name| LocalTimezone::parse(name, longitude))
+        .transpose()
+}
+
+/// Format a Julian Day in the requested timezone, if any
+fn local_timestamp(julian_day: f64, timezone: Option<&LocalTimezone>) -> Option<String> {
+    timezone.map(|tz| julian_day_to_local_timestamp(julian_day, tz))
+}
+
+/// Eclipse times as timestamps, with the eclipsed Sun or Moon's position in the given zodiac
+fn eclipse_summary(
+    eclipse: &Eclipse,
+    zodiac: ZodiacMode,
+    timezone: Option<&LocalTimezone>,
+) -> Result<EclipseSummary, String> {
+    let position = calc_planet_position(eclipse.luminary(), eclipse.maximum, zodiac)?;
+
+    Ok(EclipseSummary {
+        kind: eclipse.kind,
+        eclipse_type: eclipse.eclipse_type,
+        central: (eclipse.kind == EclipseKind::Solar).then_some(eclipse.central),
+        maximum: julian_day_to_timestamp(eclipse.maximum),
+        maximum_local: local_timestamp(eclipse.maximum, timezone),
+        begin: eclipse.begin.map(julian_day_to_timestamp),
+        end: eclipse.end.map(julian_day_to_timestamp),
+        partial_begin: eclipse.partial_begin.map(julian_day_to_timestamp),
+        partial_end: eclipse.partial_end.map(julian_day_to_timestamp),
+        totality_begin: eclipse.totality_begin.map(julian_day_to_timestamp),
+        totality_end: eclipse.totality_end.map(julian_day_to_timestamp),
+        magnitude: (eclipse.magnitude * 10000.0).round() / 10000.0,
+        penumbral_magnitude: eclipse
+            .penumbral_magnitude
+            .map(|m| (m * 10000.0).round() / 10000.0),
+        saros_series: eclipse.saros_series,
+        saros_member: eclipse.saros_member,
+        position: display_position(position.longitude),
+    })
+}
+
+/// A solar eclipse path as a GeoJSON FeatureCollection (RFC 7946), with the observer if given
+/// Lines crossing the antimeridian are split into MultiLineStrings.
+fn eclipse_geojson(
+    path: &EclipsePath,
+    eclipse: &EclipseSummary,
+    observer: Option<(&str, GeoPoint)>,
+) -> Value {
+    let coordinates = |point: &GeoPoint| {
+        json!([
+            (point.0 * 10000.0).round() / 10000.0,
+            (point.1 * 10000.0).round() / 10000.0
+        ])
+    };
+    let line = |points: &[GeoPoint]| -> Option<Value> {
+        let mut segments: Vec<Vec<Value>> = split_at_antimeridian(points)
+            .iter()
+            .filter(|segment| segment.len() > 1)
+            .map(|segment| segment.iter().map(coordinates).collect())
+            .collect();
+        match segments.len() {
+            0 => None,
+            1 => Some(json!({ "type": "LineString", "coordinates": segments.remove(0) })),
+            _ => Some(json!({ "type": "MultiLineString", "coordinates": segments })),
+        }
+    };
+    let feature = |name: &str, geometry: Value| {
+        json!({
+            "type": "Feature",
+            "geometry": geometry,
+            "properties": {
+                "name": name,
+                "eclipse": eclipse.title(),
+                "maximum": eclipse.maximum
+            }
+        })
+    };
+
+    let mut features = Vec::new();
+    if let Some(point) = path.greatest_eclipse {
+        let mut greatest = feature(
+            "greatest_eclipse",
+            json!({ "type": "Point", "coordinates": coordinates(&point) }),
+        );
+        greatest["properties"]["magnitude"] = json!(eclipse.magnitude);
+        features.push(greatest);
+    }
+    let lines = [
+        ("central_line", &path.central_line),
+        ("northern_umbral_limit", &path.umbral_limits[0]),
+        ("southern_umbral_limit", &path.umbral_limits[1]),
+        ("northern_penumbral_limit", &path.penumbral_limits[0]),
+        ("southern_penumbral_limit", &path.penumbral_limits[1]),
+    ];
+    for (name, points) in lines {
+        if let Some(geometry) = line(points) {
+            features.push(feature(name, geometry));
+        }
+    }
+    if let Some((chart, point)) = observer {
+        let mut feature = feature(
+            "observer",
+            json!({ "type": "Point", "coordinates": coordinates(&point) }),
+        );
+        feature["properties"]["chart"] = json!(chart);
+        features.push(feature);
+    }
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features
+    })
+}
+
+/// Split a path into segments wherever it crosses the antimeridian. The crossing vertex is
+/// interpolated at ±180° and ends one segment and starts the next (RFC 7946 §3.1.9).
+fn split_at_antimeridian(points: &[GeoPoint]) -> Vec<Vec<GeoPoint>> {
+    let mut segments: Vec<Vec<GeoPoint>> = Vec::new();
+    let mut previous: Option<GeoPoint> = None;
+    for &point in points {
+        match previous {
+            Some(p) if (point.0 - p.0).abs() > 180.0 => {
+                let edge = 180.0_f64.copysign(p.0);
+                let unwrapped = point.0 + 2.0 * edge;
+                let t = (edge - p.0) / (unwrapped - p.0);
+                let latitude = p.1 + t * (point.1 - p.1);
+                if let Some(segment) = segments.last_mut() {
+                    segment.push((edge, latitude));
+                }
+                segments.push(vec![(-edge, latitude)]);
+            }
+            Some(_) => {}
+            None => segments.push(Vec::new()),
+        }
+        if let Some(segment) = segments.last_mut() {
+            segment.push(point);
+        }
+        previous = Some(point);
+    }
+    segments
+}
+
+/// Julian Day for a query date and optional local time (default midnight).
+/// Without a timezone the date is taken as UTC; a repeated fall-back hour uses its first occurrence
+/// and a time skipped by a spring-forward change moves on to the first valid instant.
+fn query_julian_day(
+    date: NaiveDate,
+    time: Option<&str>,
+    timezone: Option<&str>,
+    longitude: Option<f64>,
+) -> Result<f64, String> {
+    let time = match time {
+        Some(t) => NaiveTime::parse_from_str(t, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(t, "%H:%M"))
+            .map_err(|e| format!("Invalid time format: {}. Expected HH:MM or HH:MM:SS", e))?,
+        None => NaiveTime::MIN,
+    };
+
+    match parse_timezone(timezone, longitude).map_err(|e| format!("Timezone error: {}", e))? {
+        Some(tz) => resolve_query_datetime(date, time, &tz)
+            .map(|dt| datetime_to_julian_day(dt.naive_utc()))
+            .map_err(|e| format!("Timezone error: {}", e)),
+        None => Ok(datetime_to_julian_day(NaiveDateTime::new(date, time))),
+    }
+}
+
+/// Today's date (YYYY-MM-DD) in the given timezone
+fn today_in(timezone: &str, longitude: Option<f64>) -> Result<String, String> {
+    let tz = LocalTimezone::parse(timezone, longitude)?;
+    Ok(tz
+        .from_utc(Utc::now().naive_utc())
+        .format("%Y-%m-%d")
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A server on empty storage of its own
+    fn test_server(name: &str) -> StelliumServer {
+        let dir =
+            std::env::temp_dir().join(format!("stellium-test-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        StelliumServer::with_storage(Storage::open(dir.join("natal_charts.json")).unwrap())
+    }
+
+    /// Deserialize a tool input from JSON
+    fn input<T: serde::de::DeserializeOwned>(value: Value) -> T {
+        serde_json::from_value(value).unwrap()
+    }
+
+    /// Parse a tool response, failing the test on an error response
+    fn response(output: String) -> Value {
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_ne!(value["success"], json!(false), "tool failed: {}", output);
+        value
+    }
+
+    /// The message of an error response
+    fn error(output: String) -> String {
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["success"], json!(false), "tool succeeded: {}", output);
+        value["error"].as_str().unwrap().to_string()
+    }
+
+    /// Store a chart born 1990-04-21 14:30 in New York
+    fn store_test_chart(server: &StelliumServer, name: &str) {
+        response(server.store_natal_chart(input(json!({
+            "name": name,
+            "birth_date": "1990-04-21",
+            "birth_time": "14:30:00",
+            "birth_location": "New York, NY",
+            "latitude": 40.7128,
+            "longitude": -74.006,
+            "timezone": "America/New_York"
+        }))));
+    }
+
+    #[test]
+    fn test_natal_chart_in_another_house_system() {
+        let server = test_server("house-system");
+        store_test_chart(&server, "Test");
+
+        let placidus = response(server.get_natal_chart(input(json!({"name": "Test"}))));
+        let whole_sign = response(
+            server.get_natal_chart(input(json!({"name": "Test", "house_system": "whole_sign"}))),
+        );
+        assert_eq!(placidus["positions"]["house_system"], "Placidus");
+        assert_eq!(whole_sign["positions"]["house_system"], "Whole Sign");
+
+        // Whole sign houses start at 0° of each sign; the planets don't move
+        for house in whole_sign["positions"]["houses"].as_array().unwrap() {
+            assert!(house["cusp"].as_str().unwrap().starts_with("0°"));
+        }
+        assert_eq!(
+            placidus["positions"]["sun"]["position"],
+            whole_sign["positions"]["sun"]["position"]
+        );
+
+        // The stored chart keeps its own house system
+        let stored = response(server.get_natal_chart(input(json!({"name": "Test"}))));
+        assert_eq!(stored["positions"]["house_system"], "Placidus");
+    }
+
+    #[test]
+    fn test_void_of_course_calendar_uses_local_days() {
+        let server = test_server("void-calendar");
+        let calendar = |start: &str, end: &str, timezone: &str| {
+            server.get_void_of_course_calendar(input(json!({
+                "start_date": start,
+                "end_date": end,
+                "timezone": timezone
+            })))
+        };
+
+        // The Moon is void from 2024-12-17 18:33 UTC, which is already December 18 in Tokyo
+        let periods = |output: String| response(output)["void_periods"].as_array().unwrap().clone();
+        assert!(
+            periods(calendar("2024-12-17", "2024-12-17", "UTC"))[0]["start"]
+                .as_str()
+                .unwrap()
+                .starts_with("2024-12-17T18:33")
+        );
+        assert!(periods(calendar("2024-12-17", "2024-12-17", "Asia/Tokyo")).is_empty());
+        assert!(
+            periods(calendar("2024-12-18", "2024-12-18", "Asia/Tokyo"))[0]["start"]
+                .as_str()
+                .unwrap()
+                .starts_with("2024-12-17T18:33")
+        );
+
+        // Inverted and overlong ranges are rejected
+        assert!(error(calendar("2024-12-18", "2024-12-17", "UTC")).contains("before start_date"));
+        assert!(error(calendar("2024-01-01", "2025-06-01", "UTC")).contains("at most 366 days"));
+    }
+
+    #[test]
+    fn test_per_call_orbs_are_validated() {
+        let server = test_server("orb-validation");
+        store_test_chart(&server, "Test");
+        store_test_chart(&server, "Partner");
+        let orbs = json!({"aspect_orbs": {"square": 40}});
+
+        let errors = [
+            error(server.get_daily_transits(input(json!({"date": "2024-01-01", "orbs": orbs})))),
+            error(server.get_transit_report(input(
+                json!({"start_date": "2024-01-01", "end_date": "2024-01-31", "orbs": orbs}),
+            ))),
+            error(server.get_natal_chart(input(json!({"name": "Test", "orbs": orbs})))),
+            error(server.get_compatibility(input(
+                json!({"person1_name": "Test", "person2_name": "Partner", "orbs": orbs}),
+            ))),
+            error(server.get_full_chart_analysis(input(json!({"name": "Test", "orbs": orbs})))),
+            error(server.get_relationship_transit_analysis(input(json!({
+                "person1_name": "Test",
+                "person2_name": "Partner",
+                "transit_orbs": orbs
+            })))),
+            error(
+                server.get_aspect_patterns(input(json!({"name": "Test", "orbs": {"aspects": []}}))),
+            ),
+        ];
+        for message in &errors[..6] {
+            assert!(message.contains("between 0 and 30 degrees"), "{}", message);
+        }
+        assert!(errors[6].contains("At least one aspect"));
+    }
+
+    #[test]
+    fn test_natal_aspect_grid_follows_the_orb_policy() {
+        let server = test_server("aspect-grid");
+        store_test_chart(&server, "Test");
+        let aspects_in = |chart: &Value| -> Vec<String> {
+            let mut aspects: Vec<String> = chart["aspect_grid"]
+                .as_object()
+                .unwrap()
+                .values()
+                .flat_map(|row| row.as_object().unwrap().values())
+                .map(|cell| cell["aspect"].as_str().unwrap().to_string())
+                .collect();
+            aspects.sort();
+            aspects.dedup();
+            aspects
+        };
+
+        let stored = response(server.get_natal_chart(input(json!({"name": "Test"}))));
+        assert!(aspects_in(&stored).len() > 1);
+        // The aspects are only listed once, in the grid
+        assert!(stored["positions"].get("aspects").is_none());
+
+        let trines = response(server.get_natal_chart(input(
+            json!({"name": "Test", "orbs": {"aspects": ["trine"]}}),
+        )));
+        assert_eq!(aspects_in(&trines), vec!["trine"]);
+
+        // Changing the stored natal settings changes the grid of a chart stored before
+        response(server.set_orb_settings(input(json!({"natal": {"aspects": ["square"]}}))));
+        let squares = response(server.get_natal_chart(input(json!({"name": "Test"}))));
+        assert_eq!(aspects_in(&squares), vec!["square"]);
+    }
+
+    #[test]
+    fn test_composite_midpoint() {
+        assert_eq!(midpoint(100.0, 200.0), 150.0);
+        assert_eq!(midpoint(200.0, 100.0), 150.0);
+        // The nearer midpoint, across 0° Aries
+        assert_eq!(midpoint(350.0, 10.0), 0.0);
+        assert_eq!(midpoint(10.0, 340.0), 355.0);
+        assert_eq!(midpoint(300.0, 40.0), 350.0);
+    }
+
+    #[test]
+    fn test_natal_points() {
+        let server = test_server("natal-points");
+        store_test_chart(&server, "Test");
+        let chart = server.storage.get_chart("Test").unwrap();
+        let points = |extra: &[AdditionalPoint], include_cusps: bool| -> Vec<ChartPoint> {
+            chart
+                .natal_points(extra, include_cusps)
+                .into_iter()
+                .map(|(point, _)| point)
+                .collect()
         };
 
-        if crossed {
-            return Some(jd);
+        // The planets, then the angles
+        let plain = points(&[], false);
+        assert_eq!(plain.len(), Planet::all().len() + 3);
+        assert_eq!(plain[0], ChartPoint::Planet(Planet::Sun));
+        assert_eq!(
+            plain[Planet::all().len()..],
+            [
+                ChartPoint::Ascendant,
+                ChartPoint::Midheaven,
+                ChartPoint::Vertex
+            ]
+        );
+
+        // Selected points follow the planets, once each
+        let extra = points(
+            &[
+                AdditionalPoint::SouthNode,
+                AdditionalPoint::PartOfFortune,
+                AdditionalPoint::SouthNode,
+            ],
+            false,
+        );
+        assert_eq!(
+            extra[Planet::all().len()..Planet::all().len() + 3],
+            [
+                ChartPoint::Planet(Planet::SouthNode),
+                ChartPoint::PartOfFortune,
+                ChartPoint::Ascendant
+            ]
+        );
+
+        // Cusps come last, without the 1st and 10th, which are the Ascendant and Midheaven
+        let with_cusps = points(&[], true);
+        let cusps: Vec<ChartPoint> = with_cusps[plain.len()..].to_vec();
+        assert_eq!(cusps.len(), 10);
+        assert!(
+            !cusps.contains(&ChartPoint::HouseCusp(1))
+                && !cusps.contains(&ChartPoint::HouseCusp(10))
+        );
+        assert_eq!(cusps[0], ChartPoint::HouseCusp(2));
+
+        let south_node =
+            chart.natal_points(&[AdditionalPoint::SouthNode], false)[Planet::all().len()].1;
+        let north_node = chart.planets[&Planet::NorthNode].longitude;
+        assert!((angle_past(south_node - north_node, 180.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_full_chart_analysis_recalculates_older_charts() {
+        let server = test_server("full-analysis");
+        store_test_chart(&server, "Test");
+
+        // A chart stored before the additional points were recorded
+        let mut chart = server.storage.get_chart("Test").unwrap();
+        for planet in Planet::additional() {
+            chart.planets.remove(planet);
+        }
+        chart.part_of_fortune = None;
+        chart.part_of_spirit = None;
+        server.storage.save_chart(chart).unwrap();
+
+        let analysis = response(server.get_full_chart_analysis(input(json!({
+            "name": "Test",
+            "date": "2024-01-01",
+            "points": ["south_node", "part_of_fortune"]
+        }))));
+        let natal = &analysis["natal_chart"]["positions"];
+        assert!(natal["south_node"]["position"].is_string());
+        assert!(natal["part_of_fortune"].is_string());
+    }
+
+    #[test]
+    fn test_transit_parallels() {
+        let server = test_server("transit-parallels");
+        store_test_chart(&server, "Test");
+        let orbs = json!({"parallels": true, "parallel_orb": 1.5});
+
+        let report = response(server.get_transit_report(input(json!({
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "orbs": orbs
+        }))));
+        let parallels: Vec<&Value> = report["major_events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|event| event["type"] == "parallel")
+            .collect();
+        assert!(!parallels.is_empty());
+        for event in &parallels {
+            let description = event["event"].as_str().unwrap();
+            assert!(
+                description.contains(" parallel natal ")
+                    || description.contains(" contra-parallel natal "),
+                "{}",
+                description
+            );
+            assert!(event["window"].is_object());
         }
 
-        prev_angle = current_angle;
+        let analysis = response(server.get_full_chart_analysis(input(json!({
+            "name": "Test",
+            "date": "2024-01-01",
+            "orbs": orbs
+        }))));
+        let transits = analysis["daily_transits"].as_array().unwrap();
+        assert!(transits.iter().any(|t| t["parallels_to_natal"].is_array()));
+
+        // Without parallels in the policy, neither lists them
+        let analysis = response(
+            server.get_full_chart_analysis(input(json!({"name": "Test", "date": "2024-01-01"}))),
+        );
+        assert!(analysis["daily_transits"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|t| t.get("parallels_to_natal").is_none()));
+    }
+
+    #[test]
+    fn test_eclipse_range_uses_local_days() {
+        let server = test_server("eclipse-range");
+        let eclipses = |date: &str, end_date: &str, timezone: &str| {
+            server.get_eclipses(input(json!({
+                "date": date,
+                "end_date": end_date,
+                "kind": "lunar",
+                "timezone": timezone
+            })))
+        };
+
+        // The total lunar eclipse of 2025-03-14 06:58 UTC was on the evening of March 13 in Hawaii
+        assert_eq!(
+            response(eclipses("2025-03-13", "2025-03-13", "Pacific/Honolulu"))["count"],
+            1
+        );
+        assert_eq!(
+            response(eclipses("2025-03-13", "2025-03-13", "UTC"))["count"],
+            0
+        );
+        assert_eq!(
+            response(eclipses("2025-03-14", "2025-03-14", "UTC"))["count"],
+            1
+        );
+
+        assert!(error(eclipses("2025-03-14", "2025-03-13", "UTC")).contains("before date"));
+        assert!(error(eclipses("2025-01-01", "2040-01-01", "UTC")).contains("at most 3653 days"));
+    }
+
+    #[test]
+    fn test_split_at_antimeridian() {
+        assert_eq!(
+            split_at_antimeridian(&[(10.0, 0.0), (20.0, 5.0)]),
+            vec![vec![(10.0, 0.0), (20.0, 5.0)]]
+        );
+
+        let eastward =
+            split_at_antimeridian(&[(170.0, 10.0), (178.0, 12.0), (-176.0, 14.0), (-170.0, 16.0)]);
+        assert_eq!(eastward.len(), 2);
+        let (lon, lat) = *eastward[0].last().unwrap();
+        assert_eq!(lon, 180.0);
+        assert!((lat - (12.0 + 2.0 / 3.0)).abs() < 1e-9);
+        assert_eq!(eastward[1][0], (-180.0, lat));
+        assert_eq!(eastward[1][1], (-176.0, 14.0));
+
+        let westward = split_at_antimeridian(&[(-175.0, 0.0), (175.0, 10.0)]);
+        assert_eq!(
+            westward,
+            vec![
+                vec![(-175.0, 0.0), (-180.0, 5.0)],
+                vec![(180.0, 5.0), (175.0, 10.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transit_report_uses_local_days() {
+        let server = test_server("report-range");
+        let report = |start: &str, end: &str, timezone: &str| {
+            server.get_transit_report(input(json!({
+                "start_date": start,
+                "end_date": end,
+                "timezone": timezone
+            })))
+        };
+        let full_moons = |output: String| {
+            response(output)["lunar_events"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|event| event["event"] == "Full Moon")
+                .count()
+        };
+
+        // The Full Moon of 2024-12-15 09:01 UTC was on the evening of December 14 in Hawaii
+        assert_eq!(full_moons(report("2024-12-15", "2024-12-15", "UTC")), 1);
+        assert_eq!(full_moons(report("2024-12-14", "2024-12-14", "UTC")), 0);
+        assert_eq!(
+            full_moons(report("2024-12-14", "2024-12-14", "Pacific/Honolulu")),
+            1
+        );
+
+        // Inverted and overlong ranges are rejected
+        assert!(error(report("2024-12-15", "2024-12-14", "UTC")).contains("before start_date"));
+        assert!(error(report("2024-01-01", "2025-06-01", "UTC")).contains("at most 366 days"));
+    }
+
+    #[test]
+    fn test_natal_eclipses_use_local_days() {
+        let server = test_server("natal-eclipse-range");
+        store_test_chart(&server, "Test");
+        let eclipses = |start: &str, end: &str, timezone: Option<&str>| {
+            server.get_natal_eclipses(input(json!({
+                "name": "Test",
+                "start_date": start,
+                "end_date": end,
+                "kind": "lunar",
+                "orb": 10.0,
+                "timezone": timezone
+            })))
+        };
+
+        // The lunar eclipse of 2024-09-18 02:44 UTC was on the evening of September 17 in New York
+        assert_eq!(
+            response(eclipses("2024-09-17", "2024-09-17", None))["count"],
+            1
+        );
+        assert_eq!(
+            response(eclipses("2024-09-17", "2024-09-17", Some("UTC")))["count"],
+            0
+        );
+        assert_eq!(
+            response(eclipses("2024-09-18", "2024-09-18", Some("UTC")))["count"],
+            1
+        );
+
+        assert!(error(eclipses("2024-01-01", "2040-01-01", None)).contains("at most 3653 days"));
+    }
+
+    #[test]
+    fn test_eclipse_circumstances_start_at_local_midnight() {
+        let server = test_server("eclipse-circumstances-date");
+        let maximum = |timezone: &str| {
+            response(server.get_eclipse_circumstances(input(json!({
+                "date": "2025-09-08",
+                "kind": "lunar",
+                "timezone": timezone
+            }))))["eclipse"]["maximum"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+
+        // The lunar eclipse of 2025-09-07 18:11 UTC was early on September 8 in Tokyo
+        assert!(maximum("Asia/Tokyo").starts_with("2025-09-07T18:1"));
+        assert!(maximum("UTC").starts_with("2026-03-03"));
     }
 
-    None
+    #[test]
+    fn test_retrograde_status_at_local_midnight() {
+        let server = test_server("retrograde-date");
+        let status = |timezone: &str| {
+            response(server.get_retrograde_status(input(json!({
+                "date": "2024-04-02",
+                "timezone": timezone
+            }))))
+        };
+        let lists = |status: &Value, key: &str| {
+            status[key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|p| p["planet"] == "Mercury")
+        };
+
+        // Mercury stationed retrograde on 2024-04-01 at 22:14 UTC, after midnight of April 2 in Tokyo
+        let utc = status("UTC");
+        assert!(lists(&utc, "currently_retrograde"));
+        let tokyo = status("Asia/Tokyo");
+        assert!(!lists(&tokyo, "currently_retrograde"));
+        assert!(lists(&tokyo, "upcoming_retrogrades"));
+    }
 }
@@ -1,4 +1,4 @@
-//! Persistent storage for natal charts
+//! Persistent storage for natal charts and server settings
 
 use std::collections::HashMap;
 use std::fs;
@@ -7,7 +7,7 @@ use std::sync::RwLock;
 
 use directories::ProjectDirs;
 
-use crate::models::NatalChart;
+use crate::models::{NatalChart, OrbSettings};
 
 use serde::Serialize;
 
@@ -19,12 +19,20 @@ pub struct ChartInfo {
     pub birth_location: String,
 }
 
+/// The per-user data directory, if the platform has one (e.g. ~/.local/share/stellium)
+pub fn data_dir() -> Option<PathBuf> {
+    ProjectDirs::from("com", "stellium", "stellium").map(|dirs| dirs.data_dir().to_path_buf())
+}
+
 /// Storage backend for natal charts
 /// Uses composite key (name + birth_date) to prevent duplicates
 pub struct Storage {
     /// Charts stored by composite key: "{name}_{birth_date}"
     charts: RwLock<HashMap<String, NatalChart>>,
     storage_path: PathBuf,
+    /// Default orb tables used when a tool call doesn't pass its own
+    orb_settings: RwLock<OrbSettings>,
+    orb_settings_path: PathBuf,
 }
 
 impl Storage {
@@ -35,8 +43,11 @@ impl Storage {
 
     /// Create a new storage instance
     pub fn new() -> Result<Self, String> {
-        let storage_path = Self::get_storage_path()?;
+        Self::open(Self::get_storage_path()?)
+    }
 
+    /// Open the storage kept in a given charts file (orb settings live next to it)
+    pub fn open(storage_path: PathBuf) -> Result<Self, String> {
         // Ensure directory exists
         if let Some(parent) = storage_path.parent() {
             fs::create_dir_all(parent)
@@ -52,16 +63,27 @@ impl Storage {
             HashMap::new()
         };
 
+        let orb_settings_path = storage_path.with_file_name("orb_settings.json");
+        let orb_settings: OrbSettings = if orb_settings_path.exists() {
+            let data = fs::read_to_string(&orb_settings_path)
+                .map_err(|e| format!("Failed to read orb settings file: {}", e))?;
+            serde_json::from_str(&data)
+                .map_err(|e| format!("Failed to parse orb settings file: {}", e))?
+        } else {
+            OrbSettings::default()
+        };
+
         Ok(Self {
             charts: RwLock::new(charts),
             storage_path,
+            orb_settings: RwLock::new(orb_settings),
+            orb_settings_path,
         })
     }
 
     /// Get the storage file path
     fn get_storage_path() -> Result<PathBuf, String> {
-        if let Some(proj_dirs) = ProjectDirs::from("com", "stellium", "stellium") {
-            let data_dir = proj_dirs.data_dir();
+        if let Some(data_dir) = data_dir() {
             Ok(data_dir.join("natal_charts.json"))
         } else {
             // Fallback to current directory
@@ -204,6 +226,27 @@ impl Storage {
         }
     }
 
+    /// Get the default orb settings
+    pub fn get_orb_settings(&self) -> OrbSettings {
+        self.orb_settings
+            .read()
+            .map(|settings| settings.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replace the default orb settings and save them to disk
+    pub fn set_orb_settings(&self, settings: OrbSettings) -> Result<(), String> {
+        let data = serde_json::to_string_pretty(&settings)
+            .map_err(|e| format!("Failed to serialize orb settings: {}", e))?;
+        fs::write(&self.orb_settings_path, data)
+            .map_err(|e| format!("Failed to write orb settings file: {}", e))?;
+        *self
+            .orb_settings
+            .write()
+            .map_err(|_| "Failed to acquire write lock")? = settings;
+        Ok(())
+    }
+
     /// Persist charts to disk
     fn persist(&self) -> Result<(), String> {
         let charts = self